@@ -0,0 +1,70 @@
+//! ## Cache helpers for Next Era.
+//!
+//! Next Era Solution generic cache helpers are implemented in this modules.
+//!
+
+use crate::codec::hash_sha256_hex;
+
+/// ### Delimiter between cache key segments.
+pub const CACHE_KEY_DELIMITER: &str = ":";
+
+/// ### Maximum cache key length before `hashed` falls back to a SHA-256 digest.
+pub const MAX_CACHE_KEY_LENGTH: usize = 128;
+
+/// ### Cache key builder.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::cache::CacheKey;
+///
+/// let key = CacheKey::new().segment("org").id(1).segment("user").id(5).segment("profile").build();
+/// assert_eq!(key, "org:1:user:5:profile");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CacheKey {
+    segments: Vec<String>,
+}
+
+impl CacheKey {
+    /// ### Create empty cache key.
+    pub fn new() -> Self {
+        CacheKey::default()
+    }
+
+    /// ### Append text segment.
+    pub fn segment(mut self, segment: &str) -> Self {
+        self.segments.push(segment.to_string());
+        self
+    }
+
+    /// ### Append id segment.
+    pub fn id(mut self, id: i64) -> Self {
+        self.segments.push(id.to_string());
+        self
+    }
+
+    /// ### Build delimiter-joined cache key.
+    pub fn build(&self) -> String {
+        self.segments.join(CACHE_KEY_DELIMITER)
+    }
+
+    /// ### Build cache key, hashed with SHA-256 when longer than `MAX_CACHE_KEY_LENGTH`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::cache::CacheKey;
+    ///
+    /// let key = CacheKey::new().segment("search").segment(&"x".repeat(200)).hashed();
+    /// assert_eq!(key.len(), 64);
+    /// ```
+    pub fn hashed(&self) -> String {
+        let key = self.build();
+        if key.len() > MAX_CACHE_KEY_LENGTH {
+            hash_sha256_hex(key.as_bytes())
+        } else {
+            key
+        }
+    }
+}
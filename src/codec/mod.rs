@@ -0,0 +1,138 @@
+//! ## Codec helpers for Next Era.
+//!
+//! Next Era Solution generic encoding helpers are implemented in this modules.
+//!
+
+use base64::engine::general_purpose::{URL_SAFE, URL_SAFE_NO_PAD};
+use base64::Engine;
+use sha2::{Digest, Sha256, Sha512};
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// ### Encode bytes to base32 (RFC 4648) string with padding.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::codec::encode_base32;
+/// assert_eq!(encode_base32(b"foobar"), "MZXW6YTBOI======");
+/// ```
+pub fn encode_base32(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        result.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    // Pad to a multiple of 8 characters
+    let padding = (8 - result.len() % 8) % 8;
+    result.push_str(&"=".repeat(padding));
+    result
+}
+
+/// ### Decode base32 (RFC 4648) string to bytes.
+/// #### Padding is optional and lowercase input is accepted.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::codec::decode_base32;
+/// assert_eq!(decode_base32("MZXW6YTBOI======").unwrap(), b"foobar");
+/// assert_eq!(decode_base32("mzxw6ytboi").unwrap(), b"foobar");
+/// ```
+pub fn decode_base32(s: &str) -> Result<Vec<u8>, String> {
+    let input = s.trim_end_matches('=');
+
+    // Remaining characters of the last group can only be 2, 4, 5 or 7 characters
+    if matches!(input.len() % 8, 1 | 3 | 6) {
+        return Err("Invalid base32 length".to_string());
+    }
+
+    let mut result = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in input.chars() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a as char == c.to_ascii_uppercase())
+            .ok_or_else(|| format!("Invalid base32 character: {}", c))?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(result)
+}
+
+/// ### Encode bytes to base64url (RFC 4648) string.
+/// #### If `pad` is false, "=" padding is omitted as in jwt segments.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::codec::encode_base64url;
+/// assert_eq!(encode_base64url(b"foob", true), "Zm9vYg==");
+/// assert_eq!(encode_base64url(b"foob", false), "Zm9vYg");
+/// ```
+pub fn encode_base64url(bytes: &[u8], pad: bool) -> String {
+    if pad {
+        URL_SAFE.encode(bytes)
+    } else {
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+}
+
+/// ### Decode base64url (RFC 4648) string to bytes.
+/// #### Both padded and unpadded input is accepted.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::codec::decode_base64url;
+/// assert_eq!(decode_base64url("Zm9vYg==").unwrap(), b"foob");
+/// assert_eq!(decode_base64url("Zm9vYg").unwrap(), b"foob");
+/// ```
+pub fn decode_base64url(s: &str) -> Result<Vec<u8>, String> {
+    URL_SAFE_NO_PAD
+        .decode(s.trim_end_matches('='))
+        .map_err(|e| format!("Base64 decoding failed: {}", e))
+}
+
+/// ### Hash data with SHA-256 and get lowercase hex digest.
+/// #### For fingerprinting only (cache keys, ETags), never for passwords.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::codec::hash_sha256_hex;
+/// assert_eq!(
+///     hash_sha256_hex(b"abc"),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+pub fn hash_sha256_hex(data: &[u8]) -> String {
+    encode_hex(&Sha256::digest(data))
+}
+
+/// ### Hash data with SHA-512 and get lowercase hex digest.
+/// #### For fingerprinting only (cache keys, ETags), never for passwords.
+pub fn hash_sha512_hex(data: &[u8]) -> String {
+    encode_hex(&Sha512::digest(data))
+}
+
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,83 @@
+//! ## Id helpers for Next Era.
+//!
+//! Next Era Solution generic trace id helpers are implemented in this modules.
+//!
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::codec::encode_hex;
+
+/// ### Generate random 128-bit trace id as 32 lowercase hex characters.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::ids::new_trace_id;
+/// let trace_id = new_trace_id();
+/// assert_eq!(trace_id.len(), 32);
+/// ```
+pub fn new_trace_id() -> String {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes);
+    encode_hex(&bytes)
+}
+
+/// ### Check trace id is 32 hex characters and not all zero.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::ids::is_valid_trace_id;
+/// assert!(is_valid_trace_id("4bf92f3577b34da6a3ce929d0e0e4736"));
+/// assert!(!is_valid_trace_id("not-a-trace-id"));
+/// ```
+pub fn is_valid_trace_id(trace_id: &str) -> bool {
+    trace_id.len() == 32
+        && trace_id.chars().all(|c| c.is_ascii_hexdigit())
+        && trace_id.chars().any(|c| c != '0')
+}
+
+/// ### Trace context of a request, echoed in responses and logs.
+/// `trace_id` :  32 hex characters trace id shared by all services handling the request.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::ids::TraceContext;
+///
+/// // Keep trace id from upstream service, or start a new trace
+/// let context = TraceContext::from_header(Some("4bf92f3577b34da6a3ce929d0e0e4736"));
+/// assert_eq!(context.trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+/// let context = TraceContext::from_header(None);
+/// assert_eq!(context.trace_id.len(), 32);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceContext {
+    pub trace_id: String,
+}
+
+impl TraceContext {
+    /// ### Start a new trace with a generated trace id.
+    pub fn new() -> Self {
+        TraceContext {
+            trace_id: new_trace_id(),
+        }
+    }
+
+    /// ### Propagate trace id from incoming header value.
+    /// #### Value is trimmed and lowercased. If missing or invalid, a new trace id is generated.
+    pub fn from_header(value: Option<&str>) -> Self {
+        match value.map(|v| v.trim().to_ascii_lowercase()) {
+            Some(trace_id) if is_valid_trace_id(&trace_id) => TraceContext { trace_id },
+            _ => TraceContext::new(),
+        }
+    }
+}
+
+impl Default for TraceContext {
+    fn default() -> Self {
+        TraceContext::new()
+    }
+}
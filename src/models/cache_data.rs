@@ -1,7 +1,9 @@
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 /// ### Generic cache data model for project.
 /// `data` :  your data vec.
+/// `total` :  count of cached records, `total_u64` clamps a negative value to 0.
 ///
 /// ### Example
 ///
@@ -17,3 +19,115 @@ pub struct CacheData<T> {
     pub data: Vec<T>,
     pub total: i64,
 }
+
+impl<T> Default for CacheData<T> {
+    /// ### Empty cache data with zero total.
+    /// #### `T` does not need to implement `Default`.
+    fn default() -> Self {
+        CacheData {
+            data: Vec::new(),
+            total: 0,
+        }
+    }
+}
+
+impl<T> CacheData<T> {
+    /// ### Create cache data from an unsigned total.
+    /// #### Total larger than `i64::MAX` is saturated.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::cache_data::CacheData;
+    ///
+    /// let res_data = CacheData::new(vec![1, 2, 3], 3);
+    /// assert_eq!(res_data.total_u64(), 3);
+    /// ```
+    pub fn new(data: Vec<T>, total: u64) -> Self {
+        debug_assert!(
+            total >= data.len() as u64,
+            "total must not be less than the number of items in data"
+        );
+        CacheData {
+            data,
+            total: i64::try_from(total).unwrap_or(i64::MAX),
+        }
+    }
+
+    /// ### Get total as u64.
+    /// #### Negative total is returned as 0.
+    pub fn total_u64(&self) -> u64 {
+        u64::try_from(self.total).unwrap_or(0)
+    }
+}
+
+impl<T: DeserializeOwned> CacheData<T> {
+    /// ### Parse cache data from json string.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::cache_data::CacheData;
+    ///
+    /// let cache_data = CacheData::<i32>::from_json(r#"{"data":[1,2,3],"total":3}"#).unwrap();
+    /// assert_eq!(cache_data.data, vec![1, 2, 3]);
+    /// ```
+    pub fn from_json(s: &str) -> Result<Self, String> {
+        serde_json::from_str(s).map_err(|e| e.to_string())
+    }
+}
+
+impl<T: Serialize> CacheData<T> {
+    /// ### Serialize cache data to json string.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::cache_data::CacheData;
+    ///
+    /// let cache_data = CacheData::<i32>{ data: vec![1,2,3], total: 3};
+    /// assert_eq!(cache_data.to_json().unwrap(), r#"{"data":[1,2,3],"total":3}"#);
+    /// ```
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| e.to_string())
+    }
+
+    /// ### Serialize cache data to compact json string.
+    /// #### `total` is omitted when it is zero.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::cache_data::CacheData;
+    ///
+    /// let res_data = CacheData::<i32>{ data: vec![1,2,3], total: 0};
+    /// assert_eq!(res_data.to_compact_json().unwrap(), r#"{"data":[1,2,3]}"#);
+    /// ```
+    pub fn to_compact_json(&self) -> Result<String, String> {
+        let mut value = serde_json::to_value(self).map_err(|e| e.to_string())?;
+        if self.total == 0 {
+            if let Some(object) = value.as_object_mut() {
+                object.remove("total");
+            }
+        }
+        serde_json::to_string(&value).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl<T: Serialize> CacheData<T> {
+    /// ### Serialize cache data to MessagePack bytes.
+    /// #### Requires the `msgpack` feature.
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, String> {
+        rmp_serde::to_vec(self).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl<T: DeserializeOwned> CacheData<T> {
+    /// ### Parse cache data from MessagePack bytes.
+    /// #### Requires the `msgpack` feature.
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self, String> {
+        rmp_serde::from_slice(bytes).map_err(|e| e.to_string())
+    }
+}
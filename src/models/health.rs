@@ -0,0 +1,56 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::time::Time;
+
+/// ### Health status of a service or dependency.
+/// Serialized as "up", "down" or "degraded".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+    Degraded,
+}
+
+/// ### Health check response model for `/health` endpoints.
+/// `status` :  overall status.
+/// `checks` :  status of each dependency by name.
+/// `timestamp` :  check time in utc.
+///
+/// ### Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use nextera_utils::models::health::{HealthResponse, HealthStatus};
+///
+/// let checks = HashMap::from([(String::from("database"), HealthStatus::Up)]);
+/// let health = HealthResponse::now(checks);
+/// assert_eq!(health.status, HealthStatus::Up);
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthResponse {
+    pub status: HealthStatus,
+    pub checks: HashMap<String, HealthStatus>,
+    pub timestamp: NaiveDateTime,
+}
+
+impl HealthResponse {
+    /// ### Create health response at current utc time.
+    /// #### Overall status is `Down` if any check is down, `Degraded` if any check is degraded, otherwise `Up`.
+    pub fn now(checks: HashMap<String, HealthStatus>) -> Self {
+        let status = if checks.values().any(|s| *s == HealthStatus::Down) {
+            HealthStatus::Down
+        } else if checks.values().any(|s| *s == HealthStatus::Degraded) {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Up
+        };
+        HealthResponse {
+            status,
+            checks,
+            timestamp: Time::get_utc(),
+        }
+    }
+}
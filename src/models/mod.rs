@@ -7,3 +7,9 @@ pub mod response_message;
 pub mod response_data;
 pub mod service_response;
 pub mod cache_data;
+pub mod health;
+pub mod pagination;
+pub mod prelude;
+pub mod result_response;
+pub mod sort;
+pub mod stream_response;
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+/// ### Default maximum page size for `PageRequest`.
+pub const DEFAULT_MAX_PAGE_SIZE: u32 = 100;
+
+/// ### Generic pagination request model for project.
+/// `page` :  page number, starting from 1.
+/// `page_size` :  number of items per page, clamped to the max page size (default `DEFAULT_MAX_PAGE_SIZE`).
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::models::pagination::PageRequest;
+///
+/// let page_request = PageRequest::new(3, 20);
+/// assert_eq!(page_request.offset(), 40);
+/// assert_eq!(page_request.limit(), 20);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct PageRequest {
+    pub page: u32,
+    pub page_size: u32,
+    #[serde(skip, default = "default_max_page_size")]
+    max_page_size: u32,
+}
+
+fn default_max_page_size() -> u32 {
+    DEFAULT_MAX_PAGE_SIZE
+}
+
+impl PageRequest {
+    /// ### Create page request with the default max page size.
+    pub fn new(page: u32, page_size: u32) -> Self {
+        PageRequest {
+            page,
+            page_size,
+            max_page_size: DEFAULT_MAX_PAGE_SIZE,
+        }
+    }
+
+    /// ### Set max page size that `page_size` is clamped to.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::pagination::PageRequest;
+    ///
+    /// let page_request = PageRequest::new(1, 500).with_max_page_size(200);
+    /// assert_eq!(page_request.limit(), 200);
+    /// ```
+    pub fn with_max_page_size(self, max_page_size: u32) -> Self {
+        PageRequest {
+            max_page_size,
+            ..self
+        }
+    }
+
+    /// ### Get SQL-style limit.
+    /// #### Page size is clamped between 1 and the max page size.
+    pub fn limit(&self) -> u32 {
+        self.page_size.clamp(1, self.max_page_size.max(1))
+    }
+
+    /// ### Get SQL-style offset.
+    /// #### Page 0 is treated as page 1.
+    pub fn offset(&self) -> u64 {
+        (self.page.max(1) - 1) as u64 * self.limit() as u64
+    }
+}
+
+/// ### Generic paginated response model for project.
+/// `data` :  items of the current page.
+/// `total` :  total count of items across all pages.
+/// `page` :  current page number, starting from 1.
+/// `page_size` :  number of items per page.
+/// `total_pages` :  total number of pages.
+#[derive(Debug, Serialize)]
+pub struct PaginatedResponse<T> {
+    pub data: Vec<T>,
+    pub total: i64,
+    pub page: u32,
+    pub page_size: u32,
+    pub total_pages: u32,
+}
+
+/// ### Paginate already loaded items in memory.
+/// #### Page 0 is treated as page 1. Pages beyond the end get empty data with correct totals.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::models::pagination::paginate;
+///
+/// let items = vec![1, 2, 3, 4, 5];
+/// let page = paginate(&items, 2, 2);
+/// assert_eq!(page.data, vec![3, 4]);
+/// assert_eq!(page.total_pages, 3);
+/// ```
+pub fn paginate<T: Clone>(items: &[T], page: u32, page_size: u32) -> PaginatedResponse<T> {
+    let page = page.max(1);
+    let total_pages = if page_size == 0 {
+        0
+    } else {
+        items.len().div_ceil(page_size as usize) as u32
+    };
+
+    let start = (page as usize - 1).saturating_mul(page_size as usize);
+    let data = if start < items.len() {
+        let end = start.saturating_add(page_size as usize).min(items.len());
+        items[start..end].to_vec()
+    } else {
+        Vec::new()
+    };
+
+    PaginatedResponse {
+        data,
+        total: items.len() as i64,
+        page,
+        page_size,
+        total_pages,
+    }
+}
@@ -0,0 +1,20 @@
+//! ## Models prelude.
+//!
+//! Re-exports the common models so they can be imported at once.
+//!
+//! ```
+//! use nextera_utils::models::prelude::*;
+//!
+//! let res_msg = ResponseMessage{ message: String::from("Your message") };
+//! assert_eq!(res_msg.message, String::from("Your message"));
+//! ```
+
+pub use crate::models::cache_data::CacheData;
+pub use crate::models::health::{HealthResponse, HealthStatus};
+pub use crate::models::pagination::{PageRequest, PaginatedResponse};
+pub use crate::models::response_data::ResponseData;
+pub use crate::models::response_message::ResponseMessage;
+pub use crate::models::result_response::ResultResponse;
+pub use crate::models::service_response::ServiceResponse;
+pub use crate::models::sort::{SortDirection, SortSpec};
+pub use crate::models::stream_response::StreamResponse;
@@ -1,7 +1,8 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// ### Generic response data model for project.
 /// `data` :  your data vec.
+/// `total` :  total count of records as `i64`, use `total_u64` for an unsigned count.
 ///
 /// ### Example
 ///
@@ -12,8 +13,133 @@ use serde::Serialize;
 /// assert_eq!(res_data.data.len(), 3);
 /// assert_eq!(res_data.total, 3);
 /// ```
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 pub struct ResponseData<T> {
     pub data: Vec<T>,
     pub total: i64,
 }
+
+impl<T> Default for ResponseData<T> {
+    /// ### Empty response data with zero total.
+    /// #### `T` does not need to implement `Default`.
+    fn default() -> Self {
+        ResponseData {
+            data: Vec::new(),
+            total: 0,
+        }
+    }
+}
+
+impl<T> ResponseData<T> {
+    /// ### Create response data from an unsigned total.
+    /// #### Total larger than `i64::MAX` is saturated.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::response_data::ResponseData;
+    ///
+    /// let res_data = ResponseData::new(vec![1, 2, 3], 3);
+    /// assert_eq!(res_data.total_u64(), 3);
+    /// ```
+    pub fn new(data: Vec<T>, total: u64) -> Self {
+        debug_assert!(
+            total >= data.len() as u64,
+            "total must not be less than the number of items in data"
+        );
+        ResponseData {
+            data,
+            total: i64::try_from(total).unwrap_or(i64::MAX),
+        }
+    }
+
+    /// ### Create response data, checking total against the number of items.
+    /// #### If `total` is less than the number of items in `data`, you will get an error.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::response_data::ResponseData;
+    ///
+    /// assert!(ResponseData::checked(vec![1, 2, 3], 10).is_ok());
+    /// assert!(ResponseData::checked(vec![1, 2, 3], 2).is_err());
+    /// ```
+    pub fn checked(data: Vec<T>, total: i64) -> Result<ResponseData<T>, String> {
+        if total < data.len() as i64 {
+            return Err(format!(
+                "Total {} is less than the number of items {}",
+                total,
+                data.len()
+            ));
+        }
+        Ok(ResponseData { data, total })
+    }
+
+    /// ### Create response data from an iterator of results.
+    /// #### Stops on the first error. `total` is set to the number of collected items.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::response_data::ResponseData;
+    ///
+    /// let rows: Vec<Result<i32, String>> = vec![Ok(1), Ok(2), Ok(3)];
+    /// let res_data = ResponseData::try_from_iter(rows).unwrap();
+    /// assert_eq!(res_data.total, 3);
+    /// ```
+    pub fn try_from_iter<I, E>(iter: I) -> Result<ResponseData<T>, E>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        let data = iter.into_iter().collect::<Result<Vec<T>, E>>()?;
+        let total = data.len() as i64;
+        Ok(ResponseData { data, total })
+    }
+
+    /// ### Get total as u64.
+    /// #### Negative total is returned as 0.
+    pub fn total_u64(&self) -> u64 {
+        u64::try_from(self.total).unwrap_or(0)
+    }
+}
+
+impl<T: Serialize> ResponseData<T> {
+    /// ### Serialize response data to compact json string.
+    /// #### `total` is omitted when it is zero.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::response_data::ResponseData;
+    ///
+    /// let res_data = ResponseData::<i32>{ data: vec![1,2,3], total: 0};
+    /// assert_eq!(res_data.to_compact_json().unwrap(), r#"{"data":[1,2,3]}"#);
+    /// ```
+    pub fn to_compact_json(&self) -> Result<String, String> {
+        let mut value = serde_json::to_value(self).map_err(|e| e.to_string())?;
+        if self.total == 0 {
+            if let Some(object) = value.as_object_mut() {
+                object.remove("total");
+            }
+        }
+        serde_json::to_string(&value).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl<T: Serialize> ResponseData<T> {
+    /// ### Serialize response data to MessagePack bytes.
+    /// #### Requires the `msgpack` feature.
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, String> {
+        rmp_serde::to_vec(self).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl<T: serde::de::DeserializeOwned> ResponseData<T> {
+    /// ### Parse response data from MessagePack bytes.
+    /// #### Requires the `msgpack` feature.
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self, String> {
+        rmp_serde::from_slice(bytes).map_err(|e| e.to_string())
+    }
+}
@@ -11,7 +11,7 @@ use serde::Serialize;
 /// let res_msg = ResponseMessage{ message: String::from("Your message") };
 /// assert_eq!(res_msg.message, String::from("Your message"));
 /// ```
-#[derive(Serialize)]
+#[derive(Serialize, Default)]
 pub struct ResponseMessage {
     pub message: String,
 }
@@ -0,0 +1,48 @@
+use serde::Serialize;
+
+use crate::models::response_data::ResponseData;
+use crate::models::response_message::ResponseMessage;
+
+/// ### Generic data-or-message response model for project.
+/// `Data` :  your data response.
+/// `Message` :  your message response.
+///
+/// Serialized untagged, so the JSON is exactly the inner model.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::models::result_response::ResultResponse;
+///
+/// let res = ResultResponse::<i32>::message(String::from("Your message"));
+/// let json = serde_json::to_string(&res).unwrap();
+/// assert_eq!(json, r#"{"message":"Your message"}"#);
+/// ```
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum ResultResponse<T> {
+    Data(ResponseData<T>),
+    Message(ResponseMessage),
+}
+
+impl<T> ResultResponse<T> {
+    /// ### Create data response.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::result_response::ResultResponse;
+    ///
+    /// let res = ResultResponse::data(vec![1, 2, 3], 3);
+    /// let json = serde_json::to_string(&res).unwrap();
+    /// assert_eq!(json, r#"{"data":[1,2,3],"total":3}"#);
+    /// ```
+    pub fn data(data: Vec<T>, total: i64) -> Self {
+        ResultResponse::Data(ResponseData { data, total })
+    }
+
+    /// ### Create message response.
+    pub fn message(message: String) -> Self {
+        ResultResponse::Message(ResponseMessage { message })
+    }
+}
@@ -12,8 +12,34 @@ use serde::Serialize;
 /// assert_eq!(res_msg.status_code, 200);
 /// assert_eq!(res_msg.message, String::from("Your message"));
 /// ```
-#[derive(Serialize)]
+#[derive(Serialize, Default)]
 pub struct ServiceResponse {
     pub status_code: u16,
     pub message: String,
 }
+
+#[cfg(feature = "http")]
+impl TryFrom<&ServiceResponse> for http::StatusCode {
+    type Error = String;
+
+    /// ### Convert service response status code to http status code.
+    /// #### Requires the `http` feature. Codes outside 100..=599 are rejected.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::models::service_response::ServiceResponse;
+    ///
+    /// let res = ServiceResponse{status_code: 200, message: String::from("OK") };
+    /// assert_eq!(http::StatusCode::try_from(&res), Ok(http::StatusCode::OK));
+    ///
+    /// let res = ServiceResponse{status_code: 999, message: String::from("Unknown") };
+    /// assert!(http::StatusCode::try_from(&res).is_err());
+    /// ```
+    fn try_from(response: &ServiceResponse) -> Result<Self, Self::Error> {
+        if !(100..=599).contains(&response.status_code) {
+            return Err(format!("Invalid status code: {}", response.status_code));
+        }
+        http::StatusCode::from_u16(response.status_code).map_err(|e| e.to_string())
+    }
+}
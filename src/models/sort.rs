@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+/// ### Sort direction for list endpoints.
+/// Serialized as "asc" or "desc".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// ### Generic sort model for list endpoints.
+/// `field` :  field to sort by.
+/// `direction` :  sort direction.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::models::sort::{SortDirection, SortSpec};
+///
+/// let sort = SortSpec::parse("name:desc").unwrap();
+/// assert_eq!(sort.field, String::from("name"));
+/// assert_eq!(sort.direction, SortDirection::Desc);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SortSpec {
+    pub field: String,
+    pub direction: SortDirection,
+}
+
+impl SortSpec {
+    /// ### Parse sort spec from "field:direction" string.
+    /// #### Direction is "asc" or "desc" (case-insensitive). If direction is omitted, you will get ascending.
+    pub fn parse(input: &str) -> Result<SortSpec, String> {
+        let (field, direction) = match input.split_once(':') {
+            Some((field, direction)) => (field.trim(), Some(direction.trim())),
+            None => (input.trim(), None),
+        };
+        if field.is_empty() {
+            return Err("Sort field must not be empty".to_string());
+        }
+
+        let direction = match direction.map(|d| d.to_lowercase()) {
+            None => SortDirection::Asc,
+            Some(d) if d == "asc" => SortDirection::Asc,
+            Some(d) if d == "desc" => SortDirection::Desc,
+            Some(d) => return Err(format!("Invalid sort direction: {}", d)),
+        };
+
+        Ok(SortSpec {
+            field: field.to_string(),
+            direction,
+        })
+    }
+}
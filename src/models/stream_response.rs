@@ -0,0 +1,57 @@
+use serde::Serialize;
+use std::io::Write;
+
+/// ### Streaming alternative to `ResponseData` for large results.
+/// `data` :  iterator of items, consumed while writing.
+/// `total` :  known total count of records, written after the items.
+///
+/// Items are serialized one by one by `write_json`, so the whole result is never collected into a Vec.
+/// The json has the same shape as `ResponseData`.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::models::stream_response::StreamResponse;
+///
+/// let stream = StreamResponse::new(1..=3, 3);
+/// let mut buffer = Vec::new();
+/// stream.write_json(&mut buffer).unwrap();
+/// assert_eq!(String::from_utf8(buffer).unwrap(), r#"{"data":[1,2,3],"total":3}"#);
+/// ```
+pub struct StreamResponse<'a, T> {
+    pub data: Box<dyn Iterator<Item = T> + 'a>,
+    pub total: i64,
+}
+
+impl<'a, T> StreamResponse<'a, T> {
+    /// ### Create stream response from an iterator and its known total.
+    /// #### Total larger than `i64::MAX` is saturated.
+    pub fn new<I>(data: I, total: u64) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'a,
+    {
+        StreamResponse {
+            data: Box::new(data.into_iter()),
+            total: i64::try_from(total).unwrap_or(i64::MAX),
+        }
+    }
+}
+
+impl<T: Serialize> StreamResponse<'_, T> {
+    /// ### Serialize stream response as json to a writer, one item at a time.
+    /// #### Wrap unbuffered writers like files or sockets in `BufWriter`.
+    pub fn write_json<W: Write>(self, mut writer: W) -> Result<(), String> {
+        writer
+            .write_all(b"{\"data\":[")
+            .map_err(|e| e.to_string())?;
+        for (index, item) in self.data.enumerate() {
+            if index > 0 {
+                writer.write_all(b",").map_err(|e| e.to_string())?;
+            }
+            serde_json::to_writer(&mut writer, &item).map_err(|e| e.to_string())?;
+        }
+        write!(writer, "],\"total\":{}}}", self.total).map_err(|e| e.to_string())?;
+        writer.flush().map_err(|e| e.to_string())
+    }
+}
@@ -2,8 +2,17 @@
 //!
 //! Next Era Solution generic parser are implemented in these modules.
 //!
+use std::collections::HashMap;
+use std::num::{NonZeroI32, NonZeroU32, NonZeroU64};
+
 pub trait OptionParserExtensions {
     fn to_opt_i32(self) -> Option<i32>;
+    fn to_opt_bool(self) -> Option<bool>;
+    fn to_opt_nonzero_u32(self) -> Option<NonZeroU32>;
+    fn to_opt_nonzero_i32(self) -> Option<NonZeroI32>;
+    fn to_opt_nonzero_u64(self) -> Option<NonZeroU64>;
+    fn to_opt_percentage(self, strict: bool) -> Option<f64>;
+    fn parse_byte_size(self) -> Option<u64>;
 }
 
 impl OptionParserExtensions for Option<&str> {
@@ -28,10 +37,91 @@ impl OptionParserExtensions for Option<&str> {
             },
         }
     }
+
+    /// ### Parsed form optional immutable str to option bool.
+    /// #### Accepted values (case-insensitive):
+    /// #### true : "true", "1", "yes", "y", "on", "t"
+    /// #### false : "false", "0", "no", "n", "off", "f"
+    /// #### If value contain None or Failed, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::OptionParserExtensions;
+    /// let x:Option<&str> = Some("Yes");
+    /// assert_eq!(x.to_opt_bool(), Some(true));
+    /// ```
+    fn to_opt_bool(self) -> Option<bool> {
+        self.and_then(parse_bool)
+    }
+
+    /// ### Parsed form optional immutable str to option non-zero u32.
+    /// #### If value contain None, zero or Failed, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::OptionParserExtensions;
+    /// let x:Option<&str> = Some("5");
+    /// assert_eq!(x.to_opt_nonzero_u32().map(|v| v.get()), Some(5));
+    /// assert_eq!(Some("0").to_opt_nonzero_u32(), None);
+    /// ```
+    fn to_opt_nonzero_u32(self) -> Option<NonZeroU32> {
+        self.and_then(|s| s.parse::<NonZeroU32>().ok())
+    }
+
+    /// ### Parsed form optional immutable str to option non-zero i32.
+    /// #### If value contain None, zero or Failed, you will get None.
+    fn to_opt_nonzero_i32(self) -> Option<NonZeroI32> {
+        self.and_then(|s| s.parse::<NonZeroI32>().ok())
+    }
+
+    /// ### Parsed form optional immutable str to option non-zero u64.
+    /// #### If value contain None, zero or Failed, you will get None.
+    fn to_opt_nonzero_u64(self) -> Option<NonZeroU64> {
+        self.and_then(|s| s.parse::<NonZeroU64>().ok())
+    }
+
+    /// ### Parsed form optional immutable str percentage to option fraction.
+    /// #### "25%" and "0.25" both give 0.25. If `strict` is true, values outside 0% to 100% are rejected.
+    /// #### If value contain None or Failed, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::OptionParserExtensions;
+    /// assert_eq!(Some("25%").to_opt_percentage(true), Some(0.25));
+    /// assert_eq!(Some("0.25").to_opt_percentage(true), Some(0.25));
+    /// assert_eq!(Some("150%").to_opt_percentage(true), None);
+    /// ```
+    fn to_opt_percentage(self, strict: bool) -> Option<f64> {
+        self.and_then(|s| parse_percentage(s, strict))
+    }
+
+    /// ### Parsed form optional immutable str byte size like "10MB" to option byte count.
+    /// #### Decimal units (KB, MB, GB, TB) are powers of 1000, binary units (KiB, MiB, GiB, TiB) are powers of 1024.
+    /// #### Units are case-insensitive and number without unit is bytes. If value contain None or Failed, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::OptionParserExtensions;
+    /// assert_eq!(Some("10MB").parse_byte_size(), Some(10_000_000));
+    /// assert_eq!(Some("1KiB").parse_byte_size(), Some(1024));
+    /// ```
+    fn parse_byte_size(self) -> Option<u64> {
+        self.and_then(parse_byte_size)
+    }
 }
 
 pub trait ParserExtensions {
     fn to_opt_u16(self) -> Option<u16>;
+    fn to_opt_bool(self) -> Option<bool>;
+    fn to_opt_nonzero_u32(self) -> Option<NonZeroU32>;
+    fn to_opt_nonzero_i32(self) -> Option<NonZeroI32>;
+    fn to_opt_nonzero_u64(self) -> Option<NonZeroU64>;
+    fn to_opt_percentage(self, strict: bool) -> Option<f64>;
+    fn parse_byte_size(self) -> Option<u64>;
 }
 
 impl ParserExtensions for String {
@@ -54,4 +144,257 @@ impl ParserExtensions for String {
             Err(_) => None,
         }
     }
+
+    /// ### Parsed form String to bool.
+    /// #### Accepted values (case-insensitive):
+    /// #### true : "true", "1", "yes", "y", "on", "t"
+    /// #### false : "false", "0", "no", "n", "off", "f"
+    /// #### If Failed, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::ParserExtensions;
+    /// let x:String = String::from("off");
+    /// assert_eq!(x.to_opt_bool(), Some(false));
+    /// ```
+    fn to_opt_bool(self) -> Option<bool> {
+        parse_bool(&self)
+    }
+
+    /// ### Parsed form String to non-zero u32.
+    /// #### If zero or Failed, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::ParserExtensions;
+    /// let x:String = String::from("5");
+    /// assert_eq!(x.to_opt_nonzero_u32().map(|v| v.get()), Some(5));
+    /// ```
+    fn to_opt_nonzero_u32(self) -> Option<NonZeroU32> {
+        self.parse::<NonZeroU32>().ok()
+    }
+
+    /// ### Parsed form String to non-zero i32.
+    /// #### If zero or Failed, you will get None.
+    fn to_opt_nonzero_i32(self) -> Option<NonZeroI32> {
+        self.parse::<NonZeroI32>().ok()
+    }
+
+    /// ### Parsed form String to non-zero u64.
+    /// #### If zero or Failed, you will get None.
+    fn to_opt_nonzero_u64(self) -> Option<NonZeroU64> {
+        self.parse::<NonZeroU64>().ok()
+    }
+
+    /// ### Parsed form String percentage to fraction.
+    /// #### "25%" and "0.25" both give 0.25. If `strict` is true, values outside 0% to 100% are rejected.
+    /// #### If Failed, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::ParserExtensions;
+    /// let x:String = String::from("50%");
+    /// assert_eq!(x.to_opt_percentage(true), Some(0.5));
+    /// ```
+    fn to_opt_percentage(self, strict: bool) -> Option<f64> {
+        parse_percentage(&self, strict)
+    }
+
+    /// ### Parsed form String byte size like "10MB" to byte count.
+    /// #### Decimal units (KB, MB, GB, TB) are powers of 1000, binary units (KiB, MiB, GiB, TiB) are powers of 1024.
+    /// #### Units are case-insensitive and number without unit is bytes. If Failed, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::ParserExtensions;
+    /// let x:String = String::from("2GiB");
+    /// assert_eq!(x.parse_byte_size(), Some(2 * 1024 * 1024 * 1024));
+    /// ```
+    fn parse_byte_size(self) -> Option<u64> {
+        parse_byte_size(&self)
+    }
+}
+
+pub trait StrParserExtensions {
+    fn parse_csv_row(self) -> Vec<String>;
+    fn parse_query(self) -> HashMap<String, String>;
+    fn parse_kv(self, sep: char) -> Option<(String, String)>;
+}
+
+impl StrParserExtensions for &str {
+    /// ### Parsed form immutable str csv row to fields.
+    /// #### Quoted fields may contain commas and escaped quotes ("") per RFC 4180. Trailing line break is ignored.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::StrParserExtensions;
+    /// let x = r#"a,"b,c",d"#;
+    /// assert_eq!(x.parse_csv_row(), vec!["a", "b,c", "d"]);
+    /// ```
+    fn parse_csv_row(self) -> Vec<String> {
+        let row = self.trim_end_matches(['\r', '\n']);
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = row.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if in_quotes => {
+                    if chars.peek() == Some(&'"') {
+                        // Escaped quote
+                        field.push('"');
+                        chars.next();
+                    } else {
+                        in_quotes = false;
+                    }
+                }
+                '"' if field.is_empty() => in_quotes = true,
+                ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+                _ => field.push(c),
+            }
+        }
+        fields.push(field);
+        fields
+    }
+
+    /// ### Parsed form immutable str query string to key value pairs.
+    /// #### Keys and values are URL-decoded and "+" is decoded as space. Leading "?" is ignored.
+    /// #### For repeated keys the last value wins. Key without value like "flag" gets empty value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::StrParserExtensions;
+    /// let query = "a=1&name=Mg%20Mg&flag".parse_query();
+    /// assert_eq!(query.get("a"), Some(&String::from("1")));
+    /// assert_eq!(query.get("name"), Some(&String::from("Mg Mg")));
+    /// assert_eq!(query.get("flag"), Some(&String::new()));
+    /// ```
+    fn parse_query(self) -> HashMap<String, String> {
+        self.trim_start_matches('?')
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| match pair.split_once('=') {
+                Some((key, value)) => (percent_decode(key), percent_decode(value)),
+                None => (percent_decode(pair), String::new()),
+            })
+            .collect()
+    }
+
+    /// ### Parsed form immutable str config line to key value pair.
+    /// #### Split on the first `sep`, both sides are trimmed and matching surrounding quotes are removed from value.
+    /// #### If `sep` is missing or key is empty, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::parser::StrParserExtensions;
+    /// assert_eq!("KEY = \"quoted\"".parse_kv('='), Some((String::from("KEY"), String::from("quoted"))));
+    /// assert_eq!("KEY".parse_kv('='), None);
+    /// ```
+    fn parse_kv(self, sep: char) -> Option<(String, String)> {
+        let (key, value) = self.split_once(sep)?;
+        let key = key.trim();
+        if key.is_empty() {
+            return None;
+        }
+        let value = value.trim();
+        let value = ['"', '\'']
+            .iter()
+            .find_map(|quote| {
+                value
+                    .strip_prefix(*quote)
+                    .and_then(|v| v.strip_suffix(*quote))
+            })
+            .unwrap_or(value);
+        Some((key.to_string(), value.to_string()))
+    }
+}
+
+/// ### Check env flag with presence semantics.
+/// #### Precedence: missing (None) is false, then explicit false value like "0", "false", "no" or "off" is false.
+/// #### Any other value is true because the flag is present, including empty value and unknown text.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::parser::env_flag_enabled;
+/// assert!(!env_flag_enabled(None));
+/// assert!(env_flag_enabled(Some("")));
+/// assert!(env_flag_enabled(Some("1")));
+/// assert!(!env_flag_enabled(Some("false")));
+/// ```
+pub fn env_flag_enabled(value: Option<&str>) -> bool {
+    match value {
+        None => false,
+        Some(s) => parse_bool(s) != Some(false),
+    }
+}
+
+fn parse_percentage(s: &str, strict: bool) -> Option<f64> {
+    let s = s.trim();
+    let fraction = match s.strip_suffix('%') {
+        Some(percent) => percent.trim_end().parse::<f64>().ok()? / 100.0,
+        None => s.parse::<f64>().ok()?,
+    };
+    if !fraction.is_finite() || (strict && !(0.0..=1.0).contains(&fraction)) {
+        return None;
+    }
+    Some(fraction)
+}
+
+fn parse_byte_size(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let multiplier: u64 = match unit.trim().to_lowercase().as_str() {
+        "" | "b" => 1,
+        "kb" => 1_000,
+        "mb" => 1_000_000,
+        "gb" => 1_000_000_000,
+        "tb" => 1_000_000_000_000,
+        "kib" => 1 << 10,
+        "mib" => 1 << 20,
+        "gib" => 1 << 30,
+        "tib" => 1 << 40,
+        _ => return None,
+    };
+    number.parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len()
+                && bytes[i + 1].is_ascii_hexdigit()
+                && bytes[i + 2].is_ascii_hexdigit() =>
+            {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("00");
+                decoded.push(u8::from_str_radix(hex, 16).unwrap_or(0));
+                i += 2;
+            }
+            // Invalid escape like "%zz" is kept as is
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn parse_bool(s: &str) -> Option<bool> {
+    match s.trim().to_lowercase().as_str() {
+        "true" | "1" | "yes" | "y" | "on" | "t" => Some(true),
+        "false" | "0" | "no" | "n" | "off" | "f" => Some(false),
+        _ => None,
+    }
 }
@@ -1,19 +1,124 @@
 use argon2::password_hash::SaltString;
-use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
-use bcrypt::{hash, DEFAULT_COST};
-use rand::distributions::Alphanumeric;
-use rand::rngs::OsRng;
-use rand::Rng;
+use argon2::{Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier, Version};
+use bcrypt::{hash, HashParts, DEFAULT_COST};
+use rand::rngs::{OsRng, StdRng};
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// ### Minimum length of generated password.
+/// #### One character for each of lowercase, uppercase, digit and special character.
+pub const MIN_GENERATED_PASSWORD_LENGTH: usize = 4;
+
+/// ### Characters that are easily confused when printed or read aloud.
+/// #### Excluded by `generate_strong_password_unambiguous`.
+pub const AMBIGUOUS_CHARS: &str = "0Oo1lI|`";
 
 pub struct Password;
 
+/// ### Password hashing algorithm.
+/// #### Serialized as lowercase string like "argon2" and "bcrypt".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum PasswordHasherType {
     Argon2,
     Bcrypt,
 }
 
+impl FromStr for PasswordHasherType {
+    type Err = String;
+
+    /// ### Parse hasher type from config value like "argon2" or "bcrypt".
+    /// #### Case-insensitive.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::PasswordHasherType;
+    /// assert_eq!("BCRYPT".parse::<PasswordHasherType>(), Ok(PasswordHasherType::Bcrypt));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "argon2" => Ok(PasswordHasherType::Argon2),
+            "bcrypt" => Ok(PasswordHasherType::Bcrypt),
+            _ => Err(format!("Unsupported password hasher type: {}", s)),
+        }
+    }
+}
+
+/// ### Result of `verify_password_detailed`.
+/// `Match` :  password matches and hash is up to date.
+/// `Mismatch` :  password does not match.
+/// `NeedsRehash` :  password matches but hash should be replaced with a new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyOutcome {
+    Match,
+    Mismatch,
+    NeedsRehash,
+}
+
+/// ### Minimum argon2 salt length in bytes.
+pub const MIN_ARGON2_SALT_LENGTH: usize = 16;
+
+/// ### Maximum argon2 salt length in bytes (64 base64 characters in the PHC string).
+pub const MAX_ARGON2_SALT_LENGTH: usize = 48;
+
+/// ### Argon2 algorithm variant.
+/// `Argon2id` is the variant used by `hash_password`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Argon2Variant {
+    Argon2i,
+    Argon2d,
+    Argon2id,
+}
+
+impl Argon2Variant {
+    fn algorithm(&self) -> Algorithm {
+        match self {
+            Argon2Variant::Argon2i => Algorithm::Argon2i,
+            Argon2Variant::Argon2d => Algorithm::Argon2d,
+            Argon2Variant::Argon2id => Algorithm::Argon2id,
+        }
+    }
+}
+
+/// ### Argon2 cost parameters.
+/// `memory_cost` :  memory size in KiB.
+/// `time_cost` :  number of iterations.
+/// `parallelism` :  degree of parallelism.
+///
+/// Default values are the same as `Argon2::default()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params {
+    pub memory_cost: u32,
+    pub time_cost: u32,
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        Argon2Params {
+            memory_cost: Params::DEFAULT_M_COST,
+            time_cost: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl Argon2Params {
+    fn to_params(self) -> Result<Params, String> {
+        Params::new(self.memory_cost, self.time_cost, self.parallelism, None)
+            .map_err(|e| e.to_string())
+    }
+}
+
 impl Password {
     /// ### Hashing password using argon2.
+    /// #### Unicode passwords are accepted and hashed as UTF-8 bytes. Bcrypt only uses the first 72 bytes,
+    /// #### which is fewer than 72 characters for non-ASCII passwords.
     ///
     /// ### Example
     ///
@@ -54,7 +159,240 @@ impl Password {
         }
     }
 
+    /// ### Hashing password with an optional empty password guard.
+    /// #### If `reject_empty` is true, empty password is rejected instead of being hashed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Password, PasswordHasherType};
+    /// let result = Password::hash_password_guarded(String::new(), PasswordHasherType::Argon2, true);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn hash_password_guarded(
+        password: String,
+        password_hasher_type: PasswordHasherType,
+        reject_empty: bool,
+    ) -> Result<String, String> {
+        if reject_empty {
+            Password::validate_min_length(&password, 1)?;
+        }
+        Password::hash_password(password, password_hasher_type)
+    }
+
+    /// ### Hashing many passwords with the same hasher.
+    /// #### Hashes are returned in the same order as `passwords`. Stops on the first error.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Password, PasswordHasherType};
+    /// let passwords = vec![String::from("Password1"), String::from("Password2")];
+    /// let hashes = Password::hash_many(&passwords, PasswordHasherType::Argon2).unwrap();
+    /// assert_eq!(hashes.len(), 2);
+    /// ```
+    pub fn hash_many(
+        passwords: &[String],
+        password_hasher_type: PasswordHasherType,
+    ) -> Result<Vec<String>, String> {
+        Password::hash_many_with_progress(passwords, password_hasher_type, |_, _| {})
+    }
+
+    /// ### Hashing many passwords with a progress callback.
+    /// #### `progress(done, total)` is called after each password is hashed. Stops on the first error.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Password, PasswordHasherType};
+    /// let passwords = vec![String::from("Password1"), String::from("Password2")];
+    /// let hashes = Password::hash_many_with_progress(&passwords, PasswordHasherType::Argon2, |done, total| {
+    ///     println!("{}/{}", done, total);
+    /// }).unwrap();
+    /// assert_eq!(hashes.len(), 2);
+    /// ```
+    pub fn hash_many_with_progress<F>(
+        passwords: &[String],
+        password_hasher_type: PasswordHasherType,
+        mut progress: F,
+    ) -> Result<Vec<String>, String>
+    where
+        F: FnMut(usize, usize),
+    {
+        let total = passwords.len();
+        let mut hashes = Vec::with_capacity(total);
+        for password in passwords {
+            hashes.push(Password::hash_password(
+                password.clone(),
+                password_hasher_type,
+            )?);
+            progress(hashes.len(), total);
+        }
+        Ok(hashes)
+    }
+
+    /// ### Validate password has at least `min` characters.
+    /// #### Unicode characters are counted, not bytes, so "пароль" is 6 characters long.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::Password;
+    /// assert!(Password::validate_min_length("Password", 8).is_ok());
+    /// assert!(Password::validate_min_length("Pass", 8).is_err());
+    /// assert!(Password::validate_min_length("пароль", 7).is_err());
+    /// ```
+    pub fn validate_min_length(password: &str, min: usize) -> Result<(), String> {
+        if password.chars().count() < min {
+            return Err(format!(
+                "Password must be at least {} characters long.",
+                min
+            ));
+        }
+        Ok(())
+    }
+
+    /// ### Estimate password strength as a score from 0 (very weak) to 4 (very strong).
+    /// #### One point for each character class (lowercase, uppercase, digit, other) after the first,
+    /// #### plus one point each for 12 and 16 or more characters. Passwords shorter than 8 characters
+    /// #### or containing a common password like "password" or "123456" get 0.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::Password;
+    /// assert_eq!(Password::estimate_strength("password123"), 0);
+    /// assert_eq!(Password::estimate_strength("Xk2!9aBq#mZ7&wLp"), 4);
+    /// ```
+    pub fn estimate_strength(password: &str) -> u8 {
+        const COMMON_PASSWORDS: [&str; 7] = [
+            "password", "123456", "qwerty", "letmein", "admin", "welcome", "iloveyou",
+        ];
+
+        let length = password.chars().count();
+        let lowercase = password.to_lowercase();
+        if length < 8
+            || COMMON_PASSWORDS
+                .iter()
+                .any(|common| lowercase.contains(common))
+        {
+            return 0;
+        }
+
+        let classes = [
+            password.chars().any(|c| c.is_lowercase()),
+            password.chars().any(|c| c.is_uppercase()),
+            password.chars().any(|c| c.is_numeric()),
+            password.chars().any(|c| !c.is_alphanumeric()),
+        ]
+        .iter()
+        .filter(|present| **present)
+        .count();
+
+        let score =
+            classes.saturating_sub(1) + usize::from(length >= 12) + usize::from(length >= 16);
+        score.min(4) as u8
+    }
+
+    /// ### Check password is strong enough for registration.
+    /// #### Strong means `estimate_strength` is at least 3 and password has at least 12 characters.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{generate_strong_password, Password};
+    /// assert!(!Password::is_strong("password123"));
+    /// assert!(Password::is_strong(&generate_strong_password(16)));
+    /// ```
+    pub fn is_strong(password: &str) -> bool {
+        password.chars().count() >= 12 && Password::estimate_strength(password) >= 3
+    }
+
+    /// ### Hashing password using the given argon2 variant and parameters.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Argon2Params, Argon2Variant, Password};
+    /// let password = String::from("Password");
+    /// match Password::hash_password_argon2_variant(password, Argon2Variant::Argon2i, Argon2Params::default()){
+    ///     Ok(hashed_password)=>assert!(hashed_password.starts_with("$argon2i$")),
+    ///     Err(e)=>println!("Error: {}",e)
+    /// };
+    /// ```
+    pub fn hash_password_argon2_variant(
+        password: String,
+        variant: Argon2Variant,
+        params: Argon2Params,
+    ) -> Result<String, String> {
+        // Generate a random salt
+        let salt = SaltString::generate(&mut OsRng);
+
+        // Configure Argon2 with the requested variant
+        let argon2 = Argon2::new(variant.algorithm(), Version::V0x13, params.to_params()?);
+
+        // Hash the password
+        match argon2.hash_password(password.as_bytes(), &salt) {
+            Ok(password_hash) => Ok(password_hash.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// ### Hashing password using argon2 with a salt of `salt_len` bytes.
+    /// #### Salt length must be between `MIN_ARGON2_SALT_LENGTH` and `MAX_ARGON2_SALT_LENGTH`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::Password;
+    /// let password = String::from("Password");
+    /// match Password::hash_password_argon2_salt_len(password, 32){
+    ///     Ok(hashed_password)=>println!("{}" ,hashed_password),
+    ///     Err(e)=>println!("Error: {}",e)
+    /// };
+    /// ```
+    pub fn hash_password_argon2_salt_len(
+        password: String,
+        salt_len: usize,
+    ) -> Result<String, String> {
+        if !(MIN_ARGON2_SALT_LENGTH..=MAX_ARGON2_SALT_LENGTH).contains(&salt_len) {
+            return Err(format!(
+                "Salt length must be between {} and {} bytes.",
+                MIN_ARGON2_SALT_LENGTH, MAX_ARGON2_SALT_LENGTH
+            ));
+        }
+
+        // Generate a random salt of the requested length
+        let mut salt_bytes = vec![0u8; salt_len];
+        OsRng.fill(salt_bytes.as_mut_slice());
+        let salt = SaltString::encode_b64(&salt_bytes).map_err(|e| e.to_string())?;
+
+        // Hash the password
+        match Argon2::default().hash_password(password.as_bytes(), &salt) {
+            Ok(password_hash) => Ok(password_hash.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// ### Calibrate argon2 parameters for the current hardware.
+    /// #### Increases `time_cost` until a single hash takes approximately `target`, keeping the default memory cost.
+    /// #### This is meant to be run once at deploy time, not per request.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use nextera_utils::password::Password;
+    /// let params = Password::calibrate_argon2(Duration::from_millis(500));
+    /// println!("time_cost : {}", params.time_cost);
+    /// ```
+    pub fn calibrate_argon2(target: Duration) -> Argon2Params {
+        calibrate_argon2_with(target, measure_argon2)
+    }
+
     /// ### Verifying password that hashing with argon2.
+    /// #### Hashes from other tools are accepted, like PHP `password_hash` ("$2y$") and Argon2 PHC strings
+    /// #### of any variant. Malformed argon2 hash gives an error.
     ///
     /// ### Example
     ///
@@ -79,7 +417,7 @@ impl Password {
         match password_hasher_type {
             PasswordHasherType::Argon2 => {
                 // Parse the hash
-                let parsed_hash = PasswordHash::new(hash.as_str()).unwrap();
+                let parsed_hash = PasswordHash::new(hash.as_str()).map_err(|e| e.to_string())?;
 
                 // Verify the password against the hash
                 let argon2 = Argon2::default();
@@ -96,49 +434,400 @@ impl Password {
             }
         }
     }
+
+    /// ### Check password against previously used password hashes.
+    /// #### Hashing algorithm of each hash is detected from its prefix. Returns true if any hash matches.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Password, PasswordHasherType};
+    /// let previous = vec![
+    ///     Password::hash_password(String::from("OldPassword1"), PasswordHasherType::Argon2).unwrap(),
+    ///     Password::hash_password(String::from("OldPassword2"), PasswordHasherType::Bcrypt).unwrap(),
+    /// ];
+    /// assert_eq!(Password::matches_any("OldPassword2", &previous), Ok(true));
+    /// assert_eq!(Password::matches_any("NewPassword", &previous), Ok(false));
+    /// ```
+    pub fn matches_any(new_password: &str, previous_hashes: &[String]) -> Result<bool, String> {
+        for hash in previous_hashes {
+            let password_hasher_type = detect_hasher_type(hash)?;
+            if Password::verify_password(
+                hash.clone(),
+                new_password.to_string(),
+                password_hasher_type,
+            )? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// ### Verifying password against a stored hash that may be missing, in comparable time.
+    /// #### When `stored` is None or not a supported hash, a dummy argon2 hash with default parameters is verified
+    /// #### and false is returned, so response time does not reveal whether the user exists.
+    /// #### Algorithm of `stored` is detected from its prefix. For bcrypt deployments use `verify_with_dummy_with`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Password, PasswordHasherType};
+    /// let hash = Password::hash_password(String::from("Password"), PasswordHasherType::Argon2).unwrap();
+    /// assert!(Password::verify_with_dummy(Some(&hash), "Password"));
+    /// assert!(!Password::verify_with_dummy(None, "Password"));
+    /// ```
+    pub fn verify_with_dummy(stored: Option<&str>, password: &str) -> bool {
+        Password::verify_with_dummy_with(stored, password, PasswordHasherType::Argon2)
+    }
+
+    /// ### Verifying password against a stored hash that may be missing, with dummy hash of the given algorithm.
+    /// #### Same as `verify_with_dummy`, but the dummy hash uses `password_hasher_type` at the cost of `hash_password`,
+    /// #### so missing users cost the same as users hashed with that algorithm.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Password, PasswordHasherType};
+    /// let hash = Password::hash_password(String::from("Password"), PasswordHasherType::Bcrypt).unwrap();
+    /// assert!(Password::verify_with_dummy_with(Some(&hash), "Password", PasswordHasherType::Bcrypt));
+    /// assert!(!Password::verify_with_dummy_with(None, "Password", PasswordHasherType::Bcrypt));
+    /// ```
+    pub fn verify_with_dummy_with(
+        stored: Option<&str>,
+        password: &str,
+        password_hasher_type: PasswordHasherType,
+    ) -> bool {
+        if let Some(hash) = stored {
+            if let Ok(stored_hasher_type) = detect_hasher_type(hash) {
+                return Password::verify_password(
+                    hash.to_string(),
+                    password.to_string(),
+                    stored_hasher_type,
+                )
+                .unwrap_or(false);
+            }
+        }
+
+        // Spend the same work on a dummy hash and always fail
+        let _ = Password::verify_password(
+            dummy_hash(password_hasher_type).to_string(),
+            password.to_string(),
+            password_hasher_type,
+        );
+        false
+    }
+
+    /// ### Verifying password and check hash is up to date.
+    /// #### `password_hasher_type` is the algorithm new hashes should use. A matching hash of another algorithm,
+    /// #### an argon2 hash with non-default parameters or a bcrypt hash with a lower cost gives `NeedsRehash`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Password, PasswordHasherType, VerifyOutcome};
+    /// let hash = Password::hash_password(String::from("Password"), PasswordHasherType::Argon2).unwrap();
+    /// let outcome = Password::verify_password_detailed(&hash, "Password", PasswordHasherType::Argon2);
+    /// assert_eq!(outcome, Ok(VerifyOutcome::Match));
+    /// let outcome = Password::verify_password_detailed(&hash, "Password", PasswordHasherType::Bcrypt);
+    /// assert_eq!(outcome, Ok(VerifyOutcome::NeedsRehash));
+    /// ```
+    pub fn verify_password_detailed(
+        hash: &str,
+        password: &str,
+        password_hasher_type: PasswordHasherType,
+    ) -> Result<VerifyOutcome, String> {
+        let hash_type = detect_hasher_type(hash)?;
+        let (is_valid, is_current) = match hash_type {
+            PasswordHasherType::Argon2 => {
+                let parsed_hash = PasswordHash::new(hash).map_err(|e| e.to_string())?;
+                let is_valid = Argon2::default()
+                    .verify_password(password.as_bytes(), &parsed_hash)
+                    .is_ok();
+                let defaults = Params::default();
+                let is_current = parsed_hash.algorithm.as_str() == Algorithm::Argon2id.as_str()
+                    && parsed_hash.version == Some(Version::V0x13.into())
+                    && Params::try_from(&parsed_hash).is_ok_and(|params| {
+                        params.m_cost() == defaults.m_cost()
+                            && params.t_cost() == defaults.t_cost()
+                            && params.p_cost() == defaults.p_cost()
+                    });
+                (is_valid, is_current)
+            }
+            PasswordHasherType::Bcrypt => {
+                let is_valid = bcrypt::verify(password, hash).map_err(|e| e.to_string())?;
+                let cost = HashParts::from_str(hash)
+                    .map_err(|e| e.to_string())?
+                    .get_cost();
+                (is_valid, cost >= DEFAULT_COST)
+            }
+        };
+
+        if !is_valid {
+            Ok(VerifyOutcome::Mismatch)
+        } else if hash_type != password_hasher_type || !is_current {
+            Ok(VerifyOutcome::NeedsRehash)
+        } else {
+            Ok(VerifyOutcome::Match)
+        }
+    }
+
+    /// ### Hashing password using argon2 with a pepper.
+    /// #### Pepper is a server-side secret kept out of the database and passed to argon2 as its secret key.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Password, VerifyOutcome};
+    /// let hash = Password::hash_password_peppered("Password", b"pepper-2024").unwrap();
+    /// let outcome = Password::verify_password_peppers(&hash, "Password", &[b"pepper-2024"]);
+    /// assert_eq!(outcome, Ok(VerifyOutcome::Match));
+    /// ```
+    pub fn hash_password_peppered(password: &str, pepper: &[u8]) -> Result<String, String> {
+        // Generate a random salt
+        let salt = SaltString::generate(&mut OsRng);
+
+        // Configure Argon2 with the pepper as secret key
+        let argon2 = Argon2::new_with_secret(
+            pepper,
+            Algorithm::Argon2id,
+            Version::V0x13,
+            Params::default(),
+        )
+        .map_err(|e| e.to_string())?;
+
+        // Hash the password
+        match argon2.hash_password(password.as_bytes(), &salt) {
+            Ok(password_hash) => Ok(password_hash.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// ### Verifying peppered argon2 password during pepper rotation.
+    /// #### `peppers` are tried in order, newest first. Match with the first pepper gives `Match`,
+    /// #### match with an older pepper gives `NeedsRehash` so the hash can be replaced with the newest pepper.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::password::{Password, VerifyOutcome};
+    /// let hash = Password::hash_password_peppered("Password", b"pepper-2024").unwrap();
+    /// let peppers: [&[u8]; 2] = [b"pepper-2025", b"pepper-2024"];
+    /// let outcome = Password::verify_password_peppers(&hash, "Password", &peppers);
+    /// assert_eq!(outcome, Ok(VerifyOutcome::NeedsRehash));
+    /// ```
+    pub fn verify_password_peppers(
+        hash: &str,
+        password: &str,
+        peppers: &[&[u8]],
+    ) -> Result<VerifyOutcome, String> {
+        let parsed_hash = PasswordHash::new(hash).map_err(|e| e.to_string())?;
+        for (index, pepper) in peppers.iter().enumerate() {
+            let argon2 = Argon2::new_with_secret(
+                pepper,
+                Algorithm::Argon2id,
+                Version::V0x13,
+                Params::default(),
+            )
+            .map_err(|e| e.to_string())?;
+            if argon2
+                .verify_password(password.as_bytes(), &parsed_hash)
+                .is_ok()
+            {
+                return Ok(if index == 0 {
+                    VerifyOutcome::Match
+                } else {
+                    VerifyOutcome::NeedsRehash
+                });
+            }
+        }
+        Ok(VerifyOutcome::Mismatch)
+    }
+}
+
+fn detect_hasher_type(hash: &str) -> Result<PasswordHasherType, String> {
+    if hash.starts_with("$argon2") {
+        PasswordHash::new(hash).map_err(|e| e.to_string())?;
+        Ok(PasswordHasherType::Argon2)
+    } else if hash.starts_with("$2") {
+        Ok(PasswordHasherType::Bcrypt)
+    } else {
+        Err(String::from("Unsupported password hash format"))
+    }
+}
+
+fn dummy_hash(password_hasher_type: PasswordHasherType) -> &'static str {
+    static ARGON2_DUMMY_HASH: OnceLock<String> = OnceLock::new();
+    static BCRYPT_DUMMY_HASH: OnceLock<String> = OnceLock::new();
+    let dummy_hash = match password_hasher_type {
+        PasswordHasherType::Argon2 => &ARGON2_DUMMY_HASH,
+        PasswordHasherType::Bcrypt => &BCRYPT_DUMMY_HASH,
+    };
+    // Same cost as hashes from `hash_password`
+    dummy_hash.get_or_init(|| {
+        Password::hash_password(generate_strong_password(32), password_hasher_type)
+            .unwrap_or_default()
+    })
+}
+
+pub(crate) fn calibrate_argon2_with<F>(target: Duration, mut measure: F) -> Argon2Params
+where
+    F: FnMut(Argon2Params) -> Duration,
+{
+    let mut params = Argon2Params {
+        time_cost: 1,
+        ..Argon2Params::default()
+    };
+    let mut elapsed = measure(params);
+
+    while elapsed < target {
+        let next_params = Argon2Params {
+            time_cost: params.time_cost + 1,
+            ..params
+        };
+        let next_elapsed = measure(next_params);
+        if next_elapsed >= target {
+            // Pick whichever is closer to the target
+            if next_elapsed - target < target - elapsed {
+                params = next_params;
+            }
+            break;
+        }
+        params = next_params;
+        elapsed = next_elapsed;
+    }
+    params
+}
+
+fn measure_argon2(params: Argon2Params) -> Duration {
+    let start = Instant::now();
+    let _ = Password::hash_password_argon2_variant(
+        String::from("calibration password"),
+        Argon2Variant::Argon2id,
+        params,
+    );
+    start.elapsed()
+}
+
+/// ### Generated password that is masked when formatted.
+/// #### `Debug` and `Display` print "********". Use `expose` to get the actual password.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::password::generate_strong_password_masked;
+/// let password = generate_strong_password_masked(12);
+/// assert_eq!(format!("{}", password), "********");
+/// assert_eq!(password.expose().len(), 12);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct GeneratedPassword(String);
+
+impl GeneratedPassword {
+    /// ### Get the actual password.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for GeneratedPassword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("GeneratedPassword(********)")
+    }
+}
+
+impl fmt::Display for GeneratedPassword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("********")
+    }
+}
+
+/// Generates a strong password of specified length `n` wrapped in `GeneratedPassword`
+/// so it is not leaked when accidentally logged.
+pub fn generate_strong_password_masked(n: usize) -> GeneratedPassword {
+    GeneratedPassword(generate_strong_password(n))
+}
+
+/// Generates a strong password of `n` characters with `separator` inserted every `group_size` characters,
+/// e.g. "Xk2!-9aBq-...". Separators are not counted in `n` and the complexity guarantee applies to the other characters.
+/// A `group_size` of 0 inserts no separators.
+pub fn generate_strong_password_grouped(n: usize, group_size: usize, separator: char) -> String {
+    let password = generate_strong_password(n);
+    if group_size == 0 {
+        return password;
+    }
+
+    let chars: Vec<char> = password.chars().collect();
+    chars
+        .chunks(group_size)
+        .map(|group| group.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join(&separator.to_string())
 }
 
 /// Generates a strong password of specified length `n`
 /// The password contains uppercase, lowercase, digits, and special characters.
 pub fn generate_strong_password(n: usize) -> String {
+    generate_strong_password_with_rng(n, &mut rand::thread_rng(), "")
+}
+
+/// Generates a strong password of specified length `n` without characters in `AMBIGUOUS_CHARS`,
+/// e.g. "l", "1", "I", "O" and "0". Meant for passwords that are printed or read aloud.
+/// The password still contains uppercase, lowercase, digits, and special characters.
+pub fn generate_strong_password_unambiguous(n: usize) -> String {
+    generate_strong_password_with_rng(n, &mut rand::thread_rng(), AMBIGUOUS_CHARS)
+}
+
+/// Generates a strong password of specified length `n` from a seeded RNG.
+/// The same seed always gives the same password. Meant for tests only, never for real credentials.
+pub fn generate_strong_password_seeded(n: usize, seed: u64) -> String {
+    generate_strong_password_with_rng(n, &mut StdRng::seed_from_u64(seed), "")
+}
+
+fn generate_strong_password_with_rng<R: Rng>(n: usize, rng: &mut R, exclude: &str) -> String {
     // Define character groups
     const SPECIAL_CHARS: &str = "!@#$%^&*()_+{}[]:;<>,.?/|~`";
 
     // Ensure we have enough characters for a strong password
-    if n < 4 {
-        panic!("Password length must be at least 4 to ensure complexity.");
+    if n < MIN_GENERATED_PASSWORD_LENGTH {
+        panic!(
+            "Password length must be at least {} to ensure complexity.",
+            MIN_GENERATED_PASSWORD_LENGTH
+        );
     }
 
-    let mut rng = rand::thread_rng();
+    let lowercase = char_pool('a'..='z', exclude);
+    let uppercase = char_pool('A'..='Z', exclude);
+    let digits = char_pool('0'..='9', exclude);
+    let special = char_pool(SPECIAL_CHARS.chars(), exclude);
+    let lowercase_alphanumeric = [lowercase.as_slice(), digits.as_slice()].concat();
+    let uppercase_alphanumeric = [uppercase.as_slice(), digits.as_slice()].concat();
 
     // Generate at least one character from each group
     let mut password = vec![
-        (rng.sample(Alphanumeric) as char).to_ascii_lowercase(), // Lowercase
-        (rng.sample(Alphanumeric) as char).to_ascii_uppercase(), // Uppercase
-        rng.gen_range('0'..='9'),                                // Digit
-        SPECIAL_CHARS
-            .chars()
-            .nth(rng.gen_range(0..SPECIAL_CHARS.len()))
-            .unwrap(), // Special character
+        lowercase[rng.gen_range(0..lowercase.len())], // Lowercase
+        uppercase[rng.gen_range(0..uppercase.len())], // Uppercase
+        digits[rng.gen_range(0..digits.len())],       // Digit
+        special[rng.gen_range(0..special.len())],     // Special character
     ];
 
     // Fill the rest of the password with random alphanumeric or special characters
-    password.extend((0..n - 4).map(|_| {
-        let choice = rng.gen_range(0..3);
-        match choice {
-            0 => (rng.sample(Alphanumeric) as char).to_ascii_lowercase(), // Lowercase
-            1 => (rng.sample(Alphanumeric) as char).to_ascii_uppercase(), // Uppercase
-            _ => SPECIAL_CHARS
-                .chars()
-                .nth(rng.gen_range(0..SPECIAL_CHARS.len()))
-                .unwrap(), // Special
-        }
+    password.extend((0..n - MIN_GENERATED_PASSWORD_LENGTH).map(|_| {
+        let group = match rng.gen_range(0..3) {
+            0 => &lowercase_alphanumeric, // Lowercase
+            1 => &uppercase_alphanumeric, // Uppercase
+            _ => &special,                // Special
+        };
+        group[rng.gen_range(0..group.len())]
     }));
 
     // Shuffle the password to avoid predictable patterns
     use rand::seq::SliceRandom;
-    password.shuffle(&mut rng);
+    password.shuffle(rng);
 
     // Collect the password into a String and return
     password.into_iter().collect()
 }
+
+fn char_pool(chars: impl Iterator<Item = char>, exclude: &str) -> Vec<char> {
+    chars.filter(|c| !exclude.contains(*c)).collect()
+}
@@ -0,0 +1,109 @@
+//! ## Rate limit helpers for Next Era.
+//!
+//! Next Era Solution in-memory rate limiters are implemented in this modules.
+//!
+
+use chrono::{Duration, NaiveDateTime};
+use std::collections::{HashMap, VecDeque};
+
+use crate::time::Time;
+
+/// ### Sliding window rate limiter keyed by string (user id, IP, ...).
+/// #### Allows at most `max` requests per key within any `window`.
+/// #### Keys of clients that stopped sending requests are kept until `purge` is called, call it periodically.
+///
+/// ### Example
+///
+/// ```
+/// use chrono::Duration;
+/// use nextera_utils::ratelimit::SlidingWindowLimiter;
+/// use nextera_utils::time::Time;
+///
+/// let mut limiter = SlidingWindowLimiter::new(2, Duration::seconds(60));
+/// let now = Time::get_utc();
+/// assert!(limiter.check("127.0.0.1", now));
+/// assert!(limiter.check("127.0.0.1", now));
+/// assert!(!limiter.check("127.0.0.1", now));
+/// ```
+pub struct SlidingWindowLimiter {
+    max: u32,
+    window: Duration,
+    requests: HashMap<String, VecDeque<NaiveDateTime>>,
+}
+
+impl SlidingWindowLimiter {
+    /// ### Create limiter allowing `max` requests per `window`.
+    pub fn new(max: u32, window: Duration) -> Self {
+        SlidingWindowLimiter {
+            max,
+            window,
+            requests: HashMap::new(),
+        }
+    }
+
+    /// ### Check and record a request for `key` at `now`.
+    /// #### If allowed, you will get true and the request is counted. Rejected requests are not counted.
+    pub fn check(&mut self, key: &str, now: NaiveDateTime) -> bool {
+        let window = self.window;
+        let timestamps = self.requests.entry(key.to_string()).or_default();
+
+        // Prune timestamps that are outside the window
+        while timestamps
+            .front()
+            .is_some_and(|t| !Time::is_within(*t, window, now))
+        {
+            timestamps.pop_front();
+        }
+
+        if timestamps.len() >= self.max as usize {
+            if timestamps.is_empty() {
+                self.requests.remove(key);
+            }
+            return false;
+        }
+        timestamps.push_back(now);
+        true
+    }
+
+    /// ### Check and record a request for `key` at current utc time.
+    pub fn check_now(&mut self, key: &str) -> bool {
+        self.check(key, Time::get_utc())
+    }
+
+    /// ### Remove keys with no request inside the window ending at `now`.
+    /// #### You will get the number of removed keys.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use nextera_utils::ratelimit::SlidingWindowLimiter;
+    /// use nextera_utils::time::Time;
+    ///
+    /// let mut limiter = SlidingWindowLimiter::new(2, Duration::seconds(60));
+    /// let now = Time::get_utc();
+    /// limiter.check("127.0.0.1", now);
+    /// assert_eq!(limiter.purge(now + Duration::seconds(60)), 1);
+    /// assert_eq!(limiter.tracked_keys(), 0);
+    /// ```
+    pub fn purge(&mut self, now: NaiveDateTime) -> usize {
+        let window = self.window;
+        let before = self.requests.len();
+        self.requests.retain(|_, timestamps| {
+            timestamps
+                .back()
+                .is_some_and(|t| Time::is_within(*t, window, now))
+        });
+        before - self.requests.len()
+    }
+
+    /// ### Remove keys with no request inside the window ending at current utc time.
+    pub fn purge_now(&mut self) -> usize {
+        self.purge(Time::get_utc())
+    }
+
+    /// ### Get number of keys currently tracked.
+    pub fn tracked_keys(&self) -> usize {
+        self.requests.len()
+    }
+}
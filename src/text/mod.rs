@@ -0,0 +1,280 @@
+//! ## Text helpers for Next Era.
+//!
+//! Next Era Solution generic text helpers are implemented in this modules.
+//!
+
+/// ### Convert text to url slug.
+/// #### Lowercases, transliterates common accented latin letters, and joins ascii alphanumeric runs with single hyphens.
+/// #### Other characters are treated as separators.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::text::slugify;
+/// assert_eq!(slugify("My Post!"), "my-post");
+/// assert_eq!(slugify("  Crème Brûlée -- Recipe "), "creme-brulee-recipe");
+/// ```
+pub fn slugify(input: &str) -> String {
+    let mut slug = String::with_capacity(input.len());
+    let mut pending_hyphen = false;
+
+    for c in input.to_lowercase().chars() {
+        let mut buffer = [0u8; 4];
+        let part = match transliterate(c) {
+            Some(part) => part,
+            None if c.is_ascii_alphanumeric() => &*c.encode_utf8(&mut buffer),
+            None => {
+                pending_hyphen = true;
+                continue;
+            }
+        };
+        if pending_hyphen && !slug.is_empty() {
+            slug.push('-');
+        }
+        pending_hyphen = false;
+        slug.push_str(part);
+    }
+    slug
+}
+
+/// ### Convert text to camelCase.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::text::to_camel_case;
+/// assert_eq!(to_camel_case("user_id"), "userId");
+/// ```
+pub fn to_camel_case(input: &str) -> String {
+    split_words(input)
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            if i == 0 {
+                word.to_lowercase()
+            } else {
+                capitalize(word)
+            }
+        })
+        .collect()
+}
+
+/// ### Convert text to snake_case.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::text::to_snake_case;
+/// assert_eq!(to_snake_case("userId"), "user_id");
+/// ```
+pub fn to_snake_case(input: &str) -> String {
+    join_lowercase(input, "_")
+}
+
+/// ### Convert text to kebab-case.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::text::to_kebab_case;
+/// assert_eq!(to_kebab_case("UserID"), "user-id");
+/// ```
+pub fn to_kebab_case(input: &str) -> String {
+    join_lowercase(input, "-")
+}
+
+/// ### Validate email address format.
+/// #### This is a reasonable subset of RFC 5322, not a full implementation:
+/// #### single "@", non-empty local part (max 64) without spaces or misplaced dots,
+/// #### and a domain with at least one dot made of letters, digits and hyphens.
+/// #### Quoted local parts and IP address literals are not accepted.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::text::validate_email;
+/// assert!(validate_email("user@example.com"));
+/// assert!(!validate_email("no-at"));
+/// ```
+pub fn validate_email(input: &str) -> bool {
+    let Some((local, domain)) = input.split_once('@') else {
+        return false;
+    };
+    if local.is_empty() || local.len() > 64 || domain.len() > 255 || domain.contains('@') {
+        return false;
+    }
+
+    // Local part
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return false;
+    }
+    let local_allowed = |c: char| c.is_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c);
+    if !local.chars().all(local_allowed) {
+        return false;
+    }
+
+    // Domain part
+    let labels: Vec<&str> = domain.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+/// ### Mask email address for display like "j***@example.com".
+/// #### First character of local part is kept and the rest is replaced with "***", domain is kept.
+/// #### Single character local part is fully masked. Input without "@" is masked as a whole.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::text::mask_email;
+/// assert_eq!(mask_email("john@example.com"), "j***@example.com");
+/// assert_eq!(mask_email("j@example.com"), "***@example.com");
+/// ```
+pub fn mask_email(email: &str) -> String {
+    let email = email.trim();
+    let (local, domain) = match email.rsplit_once('@') {
+        Some((local, domain)) => (local, Some(domain)),
+        None => (email, None),
+    };
+    let mut chars = local.chars();
+    let masked = match (chars.next(), chars.next()) {
+        (Some(first), Some(_)) => format!("{}***", first),
+        _ => String::from("***"),
+    };
+    match domain {
+        Some(domain) => format!("{}@{}", masked, domain),
+        None => masked,
+    }
+}
+
+/// ### Normalize Myanmar mobile phone number to E.164 format.
+/// #### Accepts "09xxxxxxxx", "959xxxxxxxx" and "+959xxxxxxxx" with 7 to 9 subscriber digits after the "9" prefix.
+/// #### Spaces and hyphens are ignored. If invalid, you will get None.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::text::normalize_phone_mm;
+/// assert_eq!(normalize_phone_mm("09 123 456 789"), Some(String::from("+959123456789")));
+/// assert_eq!(normalize_phone_mm("12345"), None);
+/// ```
+pub fn normalize_phone_mm(input: &str) -> Option<String> {
+    let cleaned: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect();
+
+    let subscriber = cleaned
+        .strip_prefix("+959")
+        .or_else(|| cleaned.strip_prefix("959"))
+        .or_else(|| cleaned.strip_prefix("09"))?;
+
+    if !(7..=9).contains(&subscriber.len()) || !subscriber.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("+959{}", subscriber))
+}
+
+/// ### Format number with thousands separator like "1,234,567".
+/// #### Negative numbers keep the "-" sign in front of the first group.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::text::format_number_grouped;
+/// assert_eq!(format_number_grouped(1234567, ','), "1,234,567");
+/// assert_eq!(format_number_grouped(-1234, ' '), "-1 234");
+/// ```
+pub fn format_number_grouped(n: i64, group_sep: char) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if n < 0 {
+        grouped.push('-');
+    }
+    // Digits before the first separator, 3 when length is a multiple of 3
+    let first_group = (digits.len() + 2) % 3 + 1;
+    let (head, tail) = digits.split_at(first_group);
+    grouped.push_str(head);
+    for group in tail.as_bytes().chunks(3) {
+        grouped.push(group_sep);
+        // Digits are ascii, so each chunk is valid utf8
+        grouped.push_str(std::str::from_utf8(group).unwrap_or_default());
+    }
+    grouped
+}
+
+fn join_lowercase(input: &str, separator: &str) -> String {
+    split_words(input)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+// Split on non-alphanumeric characters and on case boundaries ("userId", "HTTPServer")
+fn split_words(input: &str) -> Vec<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn transliterate(c: char) -> Option<&'static str> {
+    let replacement = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ę' => "e",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' => "i",
+        'ñ' | 'ń' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' => "o",
+        'œ' => "oe",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' => "u",
+        'ý' | 'ÿ' => "y",
+        'ß' => "ss",
+        'ś' | 'š' => "s",
+        'ź' | 'ż' | 'ž' => "z",
+        'ł' => "l",
+        _ => return None,
+    };
+    Some(replacement)
+}
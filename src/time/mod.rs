@@ -1,4 +1,77 @@
-use chrono::{NaiveDateTime, Utc};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat, Utc,
+};
+use rand::rngs::OsRng;
+use rand::Rng;
+use std::cmp::Ordering;
+
+/// ### Supported timezone offsets.
+pub const SUPPORTED_TIMEZONES: [&str; 38] = [
+    "UTC-12:00",
+    "UTC-11:00",
+    "UTC-10:00",
+    "UTC-09:30",
+    "UTC-09:00",
+    "UTC-08:00",
+    "UTC-07:00",
+    "UTC-06:00",
+    "UTC-05:00",
+    "UTC-04:00",
+    "UTC-03:30",
+    "UTC-03:00",
+    "UTC-02:00",
+    "UTC-01:00",
+    "UTC+00:00",
+    "UTC+01:00",
+    "UTC+02:00",
+    "UTC+03:00",
+    "UTC+03:30",
+    "UTC+04:00",
+    "UTC+04:30",
+    "UTC+05:00",
+    "UTC+05:30",
+    "UTC+05:45",
+    "UTC+06:00",
+    "UTC+06:30",
+    "UTC+07:00",
+    "UTC+08:00",
+    "UTC+08:45",
+    "UTC+09:00",
+    "UTC+09:30",
+    "UTC+10:00",
+    "UTC+10:30",
+    "UTC+11:00",
+    "UTC+12:00",
+    "UTC+12:45",
+    "UTC+13:00",
+    "UTC+14:00",
+];
+
+const MY_MONTH_NAMES: [&str; 12] = [
+    "ဇန်နဝါရီ",
+    "ဖေဖော်ဝါရီ",
+    "မတ်",
+    "ဧပြီ",
+    "မေ",
+    "ဇွန်",
+    "ဇူလိုင်",
+    "ဩဂုတ်",
+    "စက်တင်ဘာ",
+    "အောက်တိုဘာ",
+    "နိုဝင်ဘာ",
+    "ဒီဇင်ဘာ",
+];
+
+// Starting from Monday, same as chrono's weekday order
+const MY_DAY_NAMES: [&str; 7] = [
+    "တနင်္လာ",
+    "အင်္ဂါ",
+    "ဗုဒ္ဓဟူး",
+    "ကြာသပတေး",
+    "သောကြာ",
+    "စနေ",
+    "တနင်္ဂနွေ",
+];
 
 pub struct Time;
 
@@ -17,4 +90,520 @@ impl Time {
         // Convert it to a naive UTC datetime
         utc_time.naive_utc()
     }
-}
\ No newline at end of file
+
+    /// ### Validate timezone offset string like "UTC+06:30".
+    /// #### If timezone is not supported, you will get "UTC+00:00".
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::time::Time;
+    /// assert_eq!(Time::validate_timezone("utc+06:30"), "UTC+06:30");
+    /// assert_eq!(Time::validate_timezone("Mars/Phobos"), "UTC+00:00");
+    /// ```
+    pub fn validate_timezone(input: &str) -> String {
+        Time::try_validate_timezone(input).unwrap_or_else(|| String::from("UTC+00:00"))
+    }
+
+    /// ### Validate timezone offset string like "UTC+06:30".
+    /// #### If timezone is not supported, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::time::Time;
+    /// assert_eq!(Time::try_validate_timezone("utc+06:30"), Some(String::from("UTC+06:30")));
+    /// assert_eq!(Time::try_validate_timezone("Mars/Phobos"), None);
+    /// ```
+    pub fn try_validate_timezone(input: &str) -> Option<String> {
+        let timezone = input.trim().to_uppercase();
+        if SUPPORTED_TIMEZONES.contains(&timezone.as_str()) {
+            Some(timezone)
+        } else {
+            None
+        }
+    }
+
+    /// ### Get timezone offset in seconds like "UTC+05:45" => 20700.
+    /// #### If timezone is not supported, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::time::Time;
+    /// assert_eq!(Time::offset_to_seconds("UTC+05:45"), Some(20700));
+    /// assert_eq!(Time::offset_to_seconds("Mars/Phobos"), None);
+    /// ```
+    pub fn offset_to_seconds(timezone: &str) -> Option<i32> {
+        let timezone = Time::try_validate_timezone(timezone)?;
+        Some(offset_minutes(&timezone) * 60)
+    }
+
+    /// ### Get timezone from offset in seconds like 20700 => "UTC+05:45".
+    /// #### If offset is not a supported timezone, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::time::Time;
+    /// assert_eq!(Time::seconds_to_offset(20700), Some(String::from("UTC+05:45")));
+    /// assert_eq!(Time::seconds_to_offset(20701), None);
+    /// ```
+    pub fn seconds_to_offset(secs: i32) -> Option<String> {
+        if secs % 60 != 0 {
+            return None;
+        }
+        let minutes = secs / 60;
+        let sign = if minutes < 0 { '-' } else { '+' };
+        let timezone = format!(
+            "UTC{}{:02}:{:02}",
+            sign,
+            minutes.abs() / 60,
+            minutes.abs() % 60
+        );
+        Time::try_validate_timezone(&timezone)
+    }
+
+    /// ### Get common abbreviation of timezone offset like "UTC+06:30" => "MMT".
+    /// #### Abbreviations are for standard time. If timezone is not supported or has no common abbreviation, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::time::Time;
+    /// assert_eq!(Time::offset_abbreviation("UTC+06:30"), Some("MMT"));
+    /// assert_eq!(Time::offset_abbreviation("UTC-12:00"), None);
+    /// ```
+    pub fn offset_abbreviation(timezone: &str) -> Option<&'static str> {
+        let timezone = Time::try_validate_timezone(timezone)?;
+        match timezone.as_str() {
+            "UTC-10:00" => Some("HST"),
+            "UTC-09:00" => Some("AKST"),
+            "UTC-08:00" => Some("PST"),
+            "UTC-07:00" => Some("MST"),
+            "UTC-06:00" => Some("CST"),
+            "UTC-05:00" => Some("EST"),
+            "UTC-04:00" => Some("AST"),
+            "UTC-03:30" => Some("NST"),
+            "UTC+00:00" => Some("UTC"),
+            "UTC+01:00" => Some("CET"),
+            "UTC+02:00" => Some("EET"),
+            "UTC+03:00" => Some("MSK"),
+            "UTC+03:30" => Some("IRST"),
+            "UTC+04:30" => Some("AFT"),
+            "UTC+05:00" => Some("PKT"),
+            "UTC+05:30" => Some("IST"),
+            "UTC+05:45" => Some("NPT"),
+            "UTC+06:30" => Some("MMT"),
+            "UTC+07:00" => Some("ICT"),
+            "UTC+08:00" => Some("SGT"),
+            "UTC+08:45" => Some("ACWST"),
+            "UTC+09:00" => Some("JST"),
+            "UTC+09:30" => Some("ACST"),
+            "UTC+10:00" => Some("AEST"),
+            "UTC+12:00" => Some("NZST"),
+            "UTC+12:45" => Some("CHAST"),
+            "UTC+14:00" => Some("LINT"),
+            _ => None,
+        }
+    }
+
+    /// ### Convert utc time to the given timezone.
+    /// #### Unsupported timezone is treated as "UTC+00:00".
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nextera_utils::time::Time;
+    /// let utc = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let result = Time::convert_timezone(utc, "UTC+06:30");
+    /// assert_eq!(result.to_string(), "2024-01-01 06:30:00");
+    /// ```
+    pub fn convert_timezone(utc: NaiveDateTime, timezone: &str) -> NaiveDateTime {
+        Time::convert_by_minutes(utc, offset_minutes(timezone))
+    }
+
+    /// ### Convert many utc times to the given timezone.
+    /// #### Timezone is parsed once. Unsupported timezone is treated as "UTC+00:00".
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nextera_utils::time::Time;
+    /// let utc = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let result = Time::convert_many(&[utc], "UTC+06:30");
+    /// assert_eq!(result[0].to_string(), "2024-01-01 06:30:00");
+    /// ```
+    pub fn convert_many(utc_times: &[NaiveDateTime], timezone: &str) -> Vec<NaiveDateTime> {
+        let offset = offset_minutes(timezone);
+        utc_times
+            .iter()
+            .map(|utc| Time::convert_by_minutes(*utc, offset))
+            .collect()
+    }
+
+    /// ### Convert utc time by offset in minutes.
+    /// #### For example 390 is "UTC+06:30" and -300 is "UTC-05:00".
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nextera_utils::time::Time;
+    /// let utc = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let result = Time::convert_by_minutes(utc, 390);
+    /// assert_eq!(result.to_string(), "2024-01-01 06:30:00");
+    /// ```
+    pub fn convert_by_minutes(utc: NaiveDateTime, offset_minutes: i32) -> NaiveDateTime {
+        utc + Duration::minutes(offset_minutes as i64)
+    }
+
+    /// ### Convert utc time to the given timezone and format it with the offset.
+    /// #### Unsupported timezone is treated as "UTC+00:00".
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nextera_utils::time::Time;
+    /// let utc = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(Time::format_with_offset(utc, "UTC+06:30"), "2024-01-01 06:30:00 +06:30");
+    /// ```
+    pub fn format_with_offset(utc: NaiveDateTime, timezone: &str) -> String {
+        let offset = offset_minutes(timezone);
+        let sign = if offset < 0 { '-' } else { '+' };
+        format!(
+            "{} {}{:02}:{:02}",
+            Time::convert_by_minutes(utc, offset).format("%Y-%m-%d %H:%M:%S"),
+            sign,
+            offset.abs() / 60,
+            offset.abs() % 60
+        )
+    }
+
+    /// ### Convert utc time to the given timezone, keeping the offset.
+    /// #### If timezone is not supported, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nextera_utils::time::Time;
+    /// let utc = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let result = Time::convert_timezone_aware(utc, "UTC+06:30").unwrap();
+    /// assert_eq!(result.to_rfc3339(), "2024-01-01T06:30:00+06:30");
+    /// assert_eq!(Time::convert_timezone_aware(utc, "Mars/Phobos"), None);
+    /// ```
+    pub fn convert_timezone_aware(
+        utc: NaiveDateTime,
+        timezone: &str,
+    ) -> Option<DateTime<FixedOffset>> {
+        let timezone = Time::try_validate_timezone(timezone)?;
+        let offset = FixedOffset::east_opt(offset_minutes(&timezone) * 60)?;
+        Some(utc.and_utc().with_timezone(&offset))
+    }
+
+    /// ### Get current time in the given timezone.
+    /// #### Unsupported timezone is treated as "UTC+00:00".
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::time::Time;
+    /// println!("{}" ,Time::now_in("UTC+06:30").to_string());
+    /// ```
+    pub fn now_in(timezone: &str) -> NaiveDateTime {
+        Time::convert_timezone(Time::get_utc(), timezone)
+    }
+
+    /// ### Format time with localized month and day names.
+    /// #### Pattern uses chrono format specifiers. Supported locales are "en" and "my" (Burmese).
+    /// #### Unknown locale falls back to "en".
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use nextera_utils::time::Time;
+    /// let dt = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(Time::format_localized(dt, "%d %B %Y", "en"), "01 January 2024");
+    /// assert_eq!(Time::format_localized(dt, "%d %B %Y", "my"), "01 ဇန်နဝါရီ 2024");
+    /// ```
+    pub fn format_localized(dt: NaiveDateTime, pattern: &str, locale: &str) -> String {
+        if !locale.eq_ignore_ascii_case("my") {
+            return dt.format(pattern).to_string();
+        }
+
+        // Replace month and day name specifiers before formatting the rest with chrono
+        let month_name = MY_MONTH_NAMES[dt.month0() as usize];
+        let day_name = MY_DAY_NAMES[dt.weekday().num_days_from_monday() as usize];
+        let mut localized_pattern = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                localized_pattern.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('B') | Some('b') | Some('h') => localized_pattern.push_str(month_name),
+                Some('A') | Some('a') => localized_pattern.push_str(day_name),
+                Some(specifier) => {
+                    localized_pattern.push('%');
+                    localized_pattern.push(specifier);
+                }
+                None => localized_pattern.push('%'),
+            }
+        }
+        dt.format(&localized_pattern).to_string()
+    }
+
+    /// ### Calculate exponential backoff delay for retries.
+    /// #### Delay is `min(base * 2^attempt, max)`. With `jitter`, a random delay between zero and that value is used (full jitter).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use nextera_utils::time::Time;
+    /// let base = Duration::from_millis(100);
+    /// let max = Duration::from_secs(10);
+    /// assert_eq!(Time::backoff_delay(3, base, max, false), Duration::from_millis(800));
+    /// ```
+    pub fn backoff_delay(
+        attempt: u32,
+        base: std::time::Duration,
+        max: std::time::Duration,
+        jitter: bool,
+    ) -> std::time::Duration {
+        let delay = 2u32
+            .checked_pow(attempt)
+            .and_then(|factor| base.checked_mul(factor))
+            .map_or(max, |delay| delay.min(max));
+        if !jitter {
+            return delay;
+        }
+        let nanos = u64::try_from(delay.as_nanos()).unwrap_or(u64::MAX);
+        std::time::Duration::from_nanos(OsRng.gen_range(0..=nanos))
+    }
+
+    /// ### Parse time from common formats.
+    /// #### Formats are tried in this order:
+    /// #### 1. RFC 3339 like "2024-01-01T06:30:00+06:30", converted to utc.
+    /// #### 2. "2024-01-01 06:30:00"
+    /// #### 3. "2024-01-01", at midnight.
+    /// #### 4. "01/31/2024" (month first), at midnight.
+    /// #### If none of them match, you will get None.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use nextera_utils::time::Time;
+    /// let result = Time::parse_flexible("2024-01-01T06:30:00+06:30").unwrap();
+    /// assert_eq!(result.to_string(), "2024-01-01 00:00:00");
+    /// assert_eq!(Time::parse_flexible("01/31/2024").unwrap().to_string(), "2024-01-31 00:00:00");
+    /// assert_eq!(Time::parse_flexible("yesterday"), None);
+    /// ```
+    pub fn parse_flexible(s: &str) -> Option<NaiveDateTime> {
+        let s = s.trim();
+        if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+            return Some(dt.naive_utc());
+        }
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+            return Some(dt);
+        }
+        ["%Y-%m-%d", "%m/%d/%Y"]
+            .iter()
+            .find_map(|pattern| NaiveDate::parse_from_str(s, pattern).ok())
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    }
+
+    /// ### Round time to the nearest multiple of `interval` since the unix epoch.
+    /// #### Ties are rounded up, so 12:32:30 rounded to 5 minutes is 12:35:00. Precision is milliseconds.
+    /// #### If `interval` is not positive, time is returned unchanged.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::{Duration, NaiveDate};
+    /// use nextera_utils::time::Time;
+    /// let dt = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(12, 37, 42).unwrap();
+    /// assert_eq!(Time::round_to(dt, Duration::minutes(5)).to_string(), "2024-01-01 12:40:00");
+    /// ```
+    pub fn round_to(dt: NaiveDateTime, interval: Duration) -> NaiveDateTime {
+        let interval_ms = interval.num_milliseconds();
+        if interval_ms <= 0 {
+            return dt;
+        }
+        let timestamp_ms = dt.and_utc().timestamp_millis();
+        let remainder = timestamp_ms.rem_euclid(interval_ms);
+        let rounded = if remainder * 2 >= interval_ms {
+            timestamp_ms - remainder + interval_ms
+        } else {
+            timestamp_ms - remainder
+        };
+        DateTime::from_timestamp_millis(rounded).map_or(dt, |rounded| rounded.naive_utc())
+    }
+
+    /// ### Format duration for humans like "1h 2m 7s".
+    /// #### Up to three largest non-zero units of days, hours, minutes and seconds are shown.
+    /// #### Durations under a second are shown in milliseconds like "250ms". Negative durations get a "-" prefix.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use nextera_utils::time::Time;
+    /// assert_eq!(Time::humanize_duration(Duration::seconds(3727)), "1h 2m 7s");
+    /// assert_eq!(Time::humanize_duration(Duration::milliseconds(250)), "250ms");
+    /// assert_eq!(Time::humanize_duration(Duration::zero()), "0s");
+    /// ```
+    pub fn humanize_duration(d: Duration) -> String {
+        let sign = if d < Duration::zero() { "-" } else { "" };
+        let d = d.abs();
+        let total_seconds = d.num_seconds();
+        if total_seconds == 0 {
+            let millis = d.num_milliseconds();
+            return if millis == 0 {
+                String::from("0s")
+            } else {
+                format!("{}{}ms", sign, millis)
+            };
+        }
+
+        let units = [
+            (total_seconds / 86_400, "d"),
+            (total_seconds % 86_400 / 3_600, "h"),
+            (total_seconds % 3_600 / 60, "m"),
+            (total_seconds % 60, "s"),
+        ];
+        let parts: Vec<String> = units
+            .iter()
+            .skip_while(|(value, _)| *value == 0)
+            .take(3)
+            .filter(|(value, _)| *value != 0)
+            .map(|(value, unit)| format!("{}{}", value, unit))
+            .collect();
+        format!("{}{}", sign, parts.join(" "))
+    }
+
+    /// ### Get whole days from now until `target`, counted from `get_utc`.
+    /// #### Partial days are floored toward zero, so 2 days and 23 hours is 2. Past target gives negative days.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use nextera_utils::time::Time;
+    /// let expires_at = Time::get_utc() + Duration::days(3) + Duration::hours(1);
+    /// assert_eq!(Time::days_until(expires_at), 3);
+    /// ```
+    pub fn days_until(target: NaiveDateTime) -> i64 {
+        (target - Self::get_utc()).num_days()
+    }
+
+    /// ### Get whole days since `past` until now, counted from `get_utc`.
+    /// #### Partial days are floored toward zero, so 2 days and 23 hours is 2. Future time gives negative days.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use nextera_utils::time::Time;
+    /// let created_at = Time::get_utc() - Duration::days(10) - Duration::hours(5);
+    /// assert_eq!(Time::days_since(created_at), 10);
+    /// ```
+    pub fn days_since(past: NaiveDateTime) -> i64 {
+        (Self::get_utc() - past).num_days()
+    }
+
+    /// ### Check time is inside the window ending at `now`.
+    /// #### Window is half-open, so time exactly `window` before `now` is outside.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use nextera_utils::time::Time;
+    /// let now = Time::get_utc();
+    /// assert!(Time::is_within(now - Duration::seconds(59), Duration::seconds(60), now));
+    /// assert!(!Time::is_within(now - Duration::seconds(60), Duration::seconds(60), now));
+    /// ```
+    pub fn is_within(dt: NaiveDateTime, window: Duration, now: NaiveDateTime) -> bool {
+        dt > now - window
+    }
+
+    /// ### Compare two local times in different timezones after normalizing to utc.
+    /// #### Unsupported timezone is treated as "UTC+00:00".
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use chrono::NaiveDate;
+    /// use nextera_utils::time::Time;
+    /// let yangon = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(6, 30, 0).unwrap();
+    /// let utc = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(Time::compare_across(yangon, "UTC+06:30", utc, "UTC+00:00"), Ordering::Equal);
+    /// ```
+    pub fn compare_across(a: NaiveDateTime, a_tz: &str, b: NaiveDateTime, b_tz: &str) -> Ordering {
+        let a_utc = a - Duration::minutes(offset_minutes(a_tz) as i64);
+        let b_utc = b - Duration::minutes(offset_minutes(b_tz) as i64);
+        a_utc.cmp(&b_utc)
+    }
+}
+
+/// ### Serde helper for serializing `NaiveDateTime` as RFC3339 in utc like "2024-01-01T12:30:00Z".
+/// #### Deserializing accepts any RFC3339 offset and converts it to utc. Time without offset is taken as utc.
+///
+/// ### Example
+///
+/// ```
+/// use chrono::{NaiveDate, NaiveDateTime};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Meta {
+///     #[serde(with = "nextera_utils::time::rfc3339")]
+///     created_at: NaiveDateTime,
+/// }
+///
+/// let created_at = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(12, 30, 0).unwrap();
+/// let json = serde_json::to_string(&Meta { created_at }).unwrap();
+/// assert_eq!(json, r#"{"created_at":"2024-01-01T12:30:00Z"}"#);
+/// ```
+pub mod rfc3339 {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// ### Serialize `NaiveDateTime` as RFC3339 utc string.
+    pub fn serialize<S>(dt: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&dt.and_utc().to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+
+    /// ### Deserialize RFC3339 string into utc `NaiveDateTime`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        if let Ok(dt) = DateTime::parse_from_rfc3339(&s) {
+            return Ok(dt.naive_utc());
+        }
+        NaiveDateTime::parse_from_str(&s, "%Y-%m-%dT%H:%M:%S%.f")
+            .map_err(|e| serde::de::Error::custom(format!("Invalid RFC3339 datetime: {}", e)))
+    }
+}
+
+fn offset_minutes(timezone: &str) -> i32 {
+    // Timezone is always in "UTC+HH:MM" format after validation
+    let timezone = Time::validate_timezone(timezone);
+    let sign = if &timezone[3..4] == "-" { -1 } else { 1 };
+    let hours: i32 = timezone[4..6].parse().unwrap_or(0);
+    let minutes: i32 = timezone[7..9].parse().unwrap_or(0);
+    sign * (hours * 60 + minutes)
+}
@@ -0,0 +1,52 @@
+//! ## TOTP helpers for Next Era.
+//!
+//! Next Era Solution time-based one-time password helpers are implemented in this modules.
+//!
+
+use crate::codec::encode_base32;
+
+/// ### Build otpauth:// provisioning uri for authenticator apps.
+/// #### Secret is base32 encoded without padding. Issuer and account are percent encoded.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::totp::provisioning_uri;
+/// let uri = provisioning_uri(b"12345678901234567890", "user@example.com", "Next Era", 6, 30);
+/// assert_eq!(
+///     uri,
+///     "otpauth://totp/Next%20Era:user%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=Next%20Era&digits=6&period=30"
+/// );
+/// ```
+pub fn provisioning_uri(
+    secret: &[u8],
+    account: &str,
+    issuer: &str,
+    digits: u32,
+    step: u64,
+) -> String {
+    let secret = encode_base32(secret);
+    let issuer = percent_encode(issuer);
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}&digits={}&period={}",
+        issuer,
+        percent_encode(account),
+        secret.trim_end_matches('='),
+        issuer,
+        digits,
+        step
+    )
+}
+
+fn percent_encode(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                result.push(byte as char)
+            }
+            _ => result.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    result
+}
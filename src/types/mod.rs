@@ -0,0 +1,75 @@
+//! ## Shared types for Next Era.
+//!
+//! Next Era Solution generic wrapper types are implemented in this modules.
+//!
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// ### String that is masked when formatted.
+/// #### Use it for model fields carrying tokens, secrets or reset links so they are not leaked when the model is logged.
+/// #### `Debug` and `Display` print "********". Serialization is unchanged, so the actual value is still sent in responses.
+/// #### Use `expose` to get the actual value.
+///
+/// ### Example
+///
+/// ```
+/// use nextera_utils::types::SensitiveString;
+///
+/// #[derive(Debug)]
+/// struct ResetMessage {
+///     message: String,
+///     reset_link: SensitiveString,
+/// }
+///
+/// let msg = ResetMessage {
+///     message: String::from("Check your email"),
+///     reset_link: SensitiveString::from("https://example.com/reset?token=abc"),
+/// };
+/// assert!(!format!("{:?}", msg).contains("token=abc"));
+/// assert_eq!(msg.reset_link.expose(), "https://example.com/reset?token=abc");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SensitiveString(String);
+
+impl SensitiveString {
+    /// ### Wrap a sensitive value.
+    pub fn new(value: String) -> Self {
+        SensitiveString(value)
+    }
+
+    /// ### Get the actual value.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// ### Unwrap into the actual value.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for SensitiveString {
+    fn from(value: String) -> Self {
+        SensitiveString(value)
+    }
+}
+
+impl From<&str> for SensitiveString {
+    fn from(value: &str) -> Self {
+        SensitiveString(value.to_string())
+    }
+}
+
+impl fmt::Debug for SensitiveString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SensitiveString(********)")
+    }
+}
+
+impl fmt::Display for SensitiveString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("********")
+    }
+}